@@ -0,0 +1,65 @@
+//! Showing profile avatars inline in terminals that support an image protocol.
+//!
+//! Kitty takes PNG data as is and iTerm2 (also understood by WezTerm) any image format,
+//! so the downloaded avatar is passed on without decoding it. The escapes are written here because
+//! viuer couldn't be added to the dependencies, which also means there is no fallback drawing the
+//! avatar with block characters in other terminals.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::env;
+
+/// Width of the shown avatar, in terminal columns
+const AVATAR_COLUMNS: u32 = 16;
+/// Kitty only accepts the image data in chunks of this many base64 bytes
+const KITTY_CHUNK_SIZE: usize = 4096;
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Image protocol understood by the terminal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageProtocol {
+    Kitty,
+    Iterm,
+}
+
+impl ImageProtocol {
+    /// Guess the protocol from the environment, None when images can't be shown
+    pub fn detect() -> Option<Self> {
+        let term = env::var("TERM").unwrap_or_default();
+        let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+        if env::var_os("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || term_program == "ghostty" {
+            Some(ImageProtocol::Kitty)
+        } else if matches!(term_program.as_str(), "iTerm.app" | "WezTerm") {
+            Some(ImageProtocol::Iterm)
+        } else {
+            None
+        }
+    }
+}
+
+/// Escape sequence drawing the image at the cursor, None when the protocol can't show its format
+pub fn image_escape(protocol: ImageProtocol, image: &[u8]) -> Option<String> {
+    let encoded = STANDARD.encode(image);
+    match protocol {
+        ImageProtocol::Kitty => {
+            if !image.starts_with(PNG_SIGNATURE) {
+                return None;
+            }
+            let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+            let mut escape = String::new();
+            for (i, chunk) in chunks.iter().enumerate() {
+                let more = u8::from(i + 1 < chunks.len());
+                let chunk = String::from_utf8_lossy(chunk);
+                if i == 0 {
+                    escape.push_str(&format!("\x1b_Gf=100,a=T,c={AVATAR_COLUMNS},m={more};{chunk}\x1b\\"));
+                } else {
+                    escape.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+                }
+            }
+            Some(escape)
+        }
+        ImageProtocol::Iterm => Some(format!(
+            "\x1b]1337;File=inline=1;size={};width={AVATAR_COLUMNS};preserveAspectRatio=1:{encoded}\x07",
+            image.len()
+        )),
+    }
+}
@@ -1,9 +1,55 @@
-use crate::{formatting, tui};
-use chrono::{Duration, Utc};
+use crate::{avatar, compose, export, filters::PostFilters, follows, formatting::{self, DisplayTimezone}, lint, mutes, opml, pager, remote, search, tui};
 use clap::{Parser, Subcommand, ValueEnum};
 use colored::*;
-use org_social_lib_rs::{feed, network, parser};
-use std::path::PathBuf;
+use org_social_lib_rs::{feed, new_post, parser, reply};
+use std::collections::HashSet;
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set from `--quiet` before running a command, like colors are set from `--color`
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Whether decorative lines are left out
+fn quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Print a decorative line, a header, count or progress message, unless `--quiet` was given
+fn print_decoration(line: impl std::fmt::Display) {
+    if !quiet() {
+        println!("{line}");
+    }
+}
+
+/// Ways a command can fail, each with its own exit code so scripts can tell them apart.
+/// The error itself is printed where it happens, this only picks the exit code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Failure {
+    /// The command couldn't do its job, e.g. a file couldn't be written (1)
+    Error,
+    /// Invalid arguments, e.g. a search pattern that isn't a valid regex (2, like clap's own errors)
+    Usage,
+    /// The social file doesn't exist or can't be read (3)
+    SocialFile,
+    /// None of the followed feeds could be fetched, nor loaded from the cache (4)
+    FetchFailed,
+    /// Nothing matched: `search` found no posts, or `unfollow`/`unmute` found no such followed or muted user (5)
+    NoMatches,
+}
+
+impl From<Failure> for ExitCode {
+    fn from(failure: Failure) -> Self {
+        ExitCode::from(match failure {
+            Failure::Error => 1,
+            Failure::Usage => 2,
+            Failure::SocialFile => 3,
+            Failure::FetchFailed => 4,
+            Failure::NoMatches => 5,
+        })
+    }
+}
 
 #[derive(Clone, ValueEnum)]
 pub enum ColorOption {
@@ -15,6 +61,53 @@ pub enum ColorOption {
     Never,
 }
 
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Pick the format automatically (currently human-readable text)
+    Auto,
+    /// Human-readable, optionally colored text
+    Text,
+    /// JSON array of posts, never colored
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    /// Standalone HTML page
+    Html,
+    /// Markdown document
+    #[value(name = "md", alias = "markdown")]
+    Markdown,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum SortOrder {
+    /// Newest posts first (default)
+    Newest,
+    /// Oldest posts first
+    Oldest,
+    /// Alphabetically by author, then newest first
+    Author,
+}
+
+/// Value of an environment variable, None when it's unset or empty
+fn env_flag(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// How the feed command presents the posts
+pub struct FeedOutput {
+    /// Number of posts to show
+    pub count: usize,
+    /// Number of posts to skip before the ones shown, for paging
+    pub offset: usize,
+    pub sort: SortOrder,
+    pub format: OutputFormat,
+    /// Show the text output through `$PAGER`
+    pub pager: bool,
+    pub timezone: DisplayTimezone,
+}
+
 #[derive(Parser)]
 #[command(name = "org-social-rs")]
 #[command(about = "An org-social reader")]
@@ -28,9 +121,21 @@ pub struct Cli {
     #[arg(short, long)]
     pub verbose: Option<bool>,
     
-    /// Control colored output
+    /// Control colored output, `auto` follows the NO_COLOR and CLICOLOR_FORCE environment variables
     #[arg(long, value_enum, default_value = "auto")]
     pub color: ColorOption,
+
+    /// Fetch remote feeds again instead of using cached copies
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Load `config-<name>.toml` from the config directory instead of `config.toml`
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Leave out headers, counts and progress messages, only print the output itself
+    #[arg(short, long)]
+    pub quiet: bool,
     
     #[command(subcommand)]
     pub command: Commands,
@@ -43,6 +148,10 @@ pub enum Commands {
         /// Number of posts to show (uses config default if not specified)
         #[arg(short, long)]
         count: Option<usize>,
+
+        /// Number of posts to skip, e.g. `--count 10 --offset 10` for the second page
+        #[arg(long, default_value = "0", conflicts_with = "watch")]
+        offset: usize,
         
         /// Show only user's own posts (don't fetch from followed users)
         #[arg(long)]
@@ -55,16 +164,158 @@ pub enum Commands {
         /// Show posts from the last N days
         #[arg(long)]
         days: Option<u32>,
+
+        /// Show only posts with this tag (repeatable, any tag matches)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+
+        /// Sort order of the posts
+        #[arg(long, value_enum, default_value = "newest")]
+        sort: SortOrder,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "auto")]
+        format: OutputFormat,
+
+        /// Keep running, printing new posts as they appear (needs a terminal)
+        #[arg(long)]
+        watch: bool,
+
+        /// Seconds between feed updates in watch mode
+        #[arg(long, default_value = "60", requires = "watch", value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+
+        /// Show the posts through `$PAGER` when printing to a terminal (always on with `pager = true` in the config)
+        #[arg(long, conflicts_with = "watch")]
+        pager: bool,
+    },
+    
+    /// Search posts by content, tags and author
+    Search {
+        /// Text to search for (case-insensitive, unless --regex is used)
+        query: String,
+
+        /// Treat the query as a regular expression
+        #[arg(long)]
+        regex: bool,
+
+        /// Search only posts from a specific source URL
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Search only posts from the last N days
+        #[arg(long)]
+        days: Option<u32>,
     },
     
     /// Show profile information
-    Profile,
+    Profile {
+        /// Also draw the avatar in terminals supporting the Kitty or iTerm2 image protocol
+        #[arg(long)]
+        image: bool,
+    },
     
     /// List followed users
-    Following,
+    Following {
+        /// Write the followed users to this file as OPML instead of listing them
+        #[arg(long, value_name = "FILE", conflicts_with = "check")]
+        export_opml: Option<PathBuf>,
+        /// Fetch every followed feed and report dead, moved or unparsable ones
+        #[arg(long)]
+        check: bool,
+    },
+    
+    /// Follow users by adding `#+FOLLOW:` lines to the social file
+    Follow {
+        /// Nick to follow the user under
+        #[arg(required_unless_present = "import_opml", conflicts_with = "import_opml")]
+        nick: Option<String>,
+        /// URL of the user's social.org file
+        #[arg(required_unless_present = "import_opml")]
+        url: Option<String>,
+        /// Follow every feed listed in this OPML file
+        #[arg(long, value_name = "FILE")]
+        import_opml: Option<PathBuf>,
+    },
     
+    /// Stop following a user by removing their `#+FOLLOW:` line from the social file
+    Unfollow {
+        /// Nick or URL of the followed user
+        target: String,
+    },
+
+    /// Hide a user's posts in the TUI without unfollowing them, or list the muted users
+    Mute {
+        /// Nick of a followed user or URL of their feed, lists the muted users when left out
+        target: Option<String>,
+    },
+
+    /// Show a muted user's posts in the TUI again
+    Unmute {
+        /// Nick of a followed user or URL of their feed
+        target: String,
+    },
+
     /// Show feed statistics
     Stats,
+
+    /// Check the config, social file and followed feeds for problems, suggesting fixes
+    Doctor,
+
+    /// Report posts of the social file with missing or duplicate IDs and malformed polls
+    Lint,
+
+    /// Create a new post without launching the TUI
+    Post {
+        /// Post content, use "-" to read it from stdin
+        #[arg(short, long)]
+        content: String,
+
+        /// Comma separated tags, # optional
+        #[arg(short, long, value_delimiter = ',')]
+        tags: Vec<String>,
+
+        /// Mood of the post
+        #[arg(short, long)]
+        mood: Option<String>,
+
+        /// Language of the post (e.g., en, es)
+        #[arg(short, long)]
+        lang: Option<String>,
+    },
+
+    /// Reply to a post without launching the TUI
+    Reply {
+        /// Full ID (url#id) of the post to reply to, or an unambiguous part of it
+        target: String,
+
+        /// Reply content, use "-" to read it from stdin
+        #[arg(short, long)]
+        content: String,
+
+        /// Comma separated tags, # optional
+        #[arg(short, long, value_delimiter = ',')]
+        tags: Vec<String>,
+
+        /// Mood of the reply
+        #[arg(short, long)]
+        mood: Option<String>,
+    },
+    
+    /// Export the feed into an HTML or Markdown document
+    Export {
+        /// Document format
+        #[arg(long, value_enum, default_value = "html")]
+        format: ExportFormat,
+
+        /// File to write the document to, prints it if not given
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Export only user's own posts (don't fetch from followed users)
+        #[arg(long)]
+        user_only: bool,
+    },
     
     /// Launch TUI interface
     Tui {
@@ -79,11 +330,23 @@ pub enum Commands {
         /// Show posts from the last N days
         #[arg(long)]
         days: Option<u32>,
+
+        /// Show only posts with this tag (repeatable, any tag matches)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+
+        /// View to start in: list, threaded, notifications, bookmarks or own (overrides the config)
+        #[arg(long)]
+        view: Option<String>,
+
+        /// Load every post of the feed, ignoring `tui_max_posts` from the config
+        #[arg(long)]
+        all: bool,
     },
 }
 
 impl Cli {
-    /// Configure color output based on the color flag
+    /// Configure color output based on the color flag, falling back to the environment
     pub fn configure_colors(&self) {
         match self.color {
             ColorOption::Always => {
@@ -93,8 +356,13 @@ impl Cli {
                 colored::control::set_override(false);
             }
             ColorOption::Auto => {
-                // Don't override, let colored crate auto-detect
-                // This is the default behavior
+                // CLICOLOR_FORCE is usually set for a single run, so it wins over a global NO_COLOR
+                if env_flag("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                    colored::control::set_override(true);
+                } else if env_flag("NO_COLOR").is_some() {
+                    colored::control::set_override(false);
+                }
+                // Otherwise let colored crate auto-detect
             }
         }
     }
@@ -103,25 +371,88 @@ impl Cli {
     pub fn file_override(&self) -> Option<PathBuf> {
         self.file.clone()
     }
+
+    /// Get the TUI's starting view override from CLI args
+    pub fn view_override(&self) -> Option<String> {
+        match &self.command {
+            Commands::Tui { view, .. } => view.clone(),
+            _ => None,
+        }
+    }
+
+    /// Check if the TUI should load every post, regardless of the configured cap
+    pub fn all_posts(&self) -> bool {
+        matches!(self.command, Commands::Tui { all: true, .. })
+    }
     
-    pub async fn handle_command(&self, user_profile: &parser::Profile, user_posts: Vec<parser::Post>, config: &crate::config::Config) {
+    /// Run the selected command, returning how it failed, if it did
+    pub async fn handle_command(&self, user_profile: &parser::Profile, user_posts: Vec<parser::Post>, config: &crate::config::Config) -> Result<(), Failure> {
+        QUIET.store(self.quiet, Ordering::Relaxed);
         let verbose = self.verbose.unwrap_or(false);
+        let fetch_options = remote::FetchOptions::from_config(config);
         match &self.command {
-            Commands::Feed { count, user_only, source, days } => {
-                let effective_count = count.unwrap_or(config.default_feed_count);
-                handle_feed_command(user_profile, user_posts, effective_count, *user_only, source.clone(), *days, verbose).await;
+            Commands::Feed { count, offset, user_only, source, days, tags, sort, format, watch, interval, pager } => {
+                let output = FeedOutput {
+                    count: count.unwrap_or(config.default_feed_count),
+                    offset: *offset,
+                    sort: *sort,
+                    format: *format,
+                    pager: *pager || config.pager,
+                    timezone: config.timezone,
+                };
+                let filters = PostFilters::new(source.clone(), *days, tags.clone());
+                if *watch {
+                    handle_feed_watch(config, user_profile, *user_only, &filters, &output, *interval, verbose).await
+                } else {
+                    handle_feed_command(user_profile, user_posts, *user_only, &filters, &output, &fetch_options, verbose).await
+                }
+            }
+            Commands::Search { query, regex, source, days } => {
+                let filters = PostFilters::new(source.clone(), *days, Vec::new());
+                handle_search_command(config, user_profile, user_posts, query, *regex, &filters, verbose).await
+            }
+            Commands::Profile { image } => {
+                handle_profile_command(user_profile, *image, &fetch_options).await;
+                Ok(())
+            }
+            Commands::Following { export_opml: Some(path), .. } => {
+                handle_export_opml_command(user_profile, path)
             }
-            Commands::Profile => {
-                handle_profile_command(user_profile);
+            Commands::Following { check: true, .. } => {
+                handle_check_following_command(user_profile, fetch_options.timeout).await
             }
-            Commands::Following => {
+            Commands::Following { export_opml: None, check: false } => {
                 handle_following_command(user_profile);
+                Ok(())
             }
+            Commands::Follow { import_opml: Some(import_opml), .. } => {
+                handle_import_opml_command(&config.social_file, user_profile, import_opml)
+            }
+            Commands::Follow { nick: Some(nick), url: Some(url), .. } => {
+                handle_follow_command(&config.social_file, user_profile, nick, url, fetch_options.timeout).await
+            }
+            Commands::Follow { .. } => unreachable!("clap requires a nick and URL without --import-opml"),
+            Commands::Unfollow { target } => handle_unfollow_command(&config.social_file, target),
+            Commands::Mute { target } => handle_mute_command(user_profile, target.as_deref()),
+            Commands::Unmute { target } => handle_unmute_command(user_profile, target),
             Commands::Stats => {
-                handle_stats_command(user_profile, &user_posts, verbose).await;
+                handle_stats_command(user_profile, &user_posts, &fetch_options, verbose).await;
+                Ok(())
+            }
+            Commands::Doctor => unreachable!("main runs the doctor before the social file is read"),
+            Commands::Lint => handle_lint_command(&config.social_file),
+            Commands::Post { content, tags, mood, lang } => {
+                handle_post_command(&config.social_file, content, tags, mood.as_deref(), lang.as_deref())
             }
-            Commands::Tui { user_only, source, days } => {
-                handle_tui_command(&config.social_file, user_profile, user_posts, *user_only, source.clone(), *days).await;
+            Commands::Reply { target, content, tags, mood } => {
+                handle_reply_command(config, user_profile, user_posts, target, content, tags, mood.as_deref()).await
+            }
+            Commands::Export { format, output, user_only } => {
+                handle_export_command(config, user_profile, user_posts, *format, output.as_deref(), *user_only, verbose).await
+            }
+            Commands::Tui { user_only, source, days, tags, .. } => {
+                let filters = PostFilters::new(source.clone(), *days, tags.clone());
+                handle_tui_command(config, user_profile, user_posts, *user_only, filters).await
             }
         }
     }
@@ -130,71 +461,309 @@ impl Cli {
 async fn handle_feed_command(
     user_profile: &parser::Profile,
     user_posts: Vec<parser::Post>,
-    count: usize,
     user_only: bool,
-    source_filter: Option<String>,
-    days_filter: Option<u32>,
+    filters: &PostFilters,
+    output: &FeedOutput,
+    fetch_options: &remote::FetchOptions,
     verbose: bool,
-) {
-    if verbose {
-        println!("{}", "Creating feed...".bright_black());
+) -> Result<(), Failure> {
+    let json = output.format == OutputFormat::Json;
+    if json {
+        // JSON output is meant for machines, never color it
+        colored::control::set_override(false);
+    }
+
+    let posts = load_feed_posts(user_profile, user_posts, user_only, filters, output, fetch_options, verbose).await?;
+    let posts_to_show: Vec<&parser::Post> = posts.iter().collect();
+
+    if json {
+        println!("{}", formatting::posts_to_json(&posts_to_show));
+        return Ok(());
+    }
+    
+    // Collected first, so it can go through the pager as a whole
+    let mut lines = Vec::new();
+    if !quiet() {
+        lines.push("=== Feed ===".cyan().bold().to_string());
+    }
+    if posts_to_show.is_empty() && output.offset > 0 {
+        if !quiet() {
+            lines.push("No more posts".bright_black().to_string());
+        }
+    } else {
+        if !quiet() {
+            lines.push(format!("Showing {} posts", posts_to_show.len()).bright_black().to_string());
+        }
+        if !posts_to_show.is_empty() {
+            lines.push(posts_text(&posts_to_show, user_profile, output.timezone));
+        }
+    }
+    pager::page_or_print(&lines.join("\n"), output.pager);
+    Ok(())
+}
+
+/// Posts separated by empty lines
+fn posts_text(posts: &[&parser::Post], user_profile: &parser::Profile, timezone: DisplayTimezone) -> String {
+    posts
+        .iter()
+        .map(|post| formatting::format_post_colored(post, Some(user_profile), timezone))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Print posts separated by empty lines
+fn print_posts(posts: &[&parser::Post], user_profile: &parser::Profile, timezone: DisplayTimezone) {
+    if !posts.is_empty() {
+        println!("{}", posts_text(posts, user_profile, timezone));
+    }
+}
+
+/// Create the feed and return the posts to show, filtered, sorted and limited to the requested page
+async fn load_feed_posts(
+    user_profile: &parser::Profile,
+    user_posts: Vec<parser::Post>,
+    user_only: bool,
+    filters: &PostFilters,
+    output: &FeedOutput,
+    fetch_options: &remote::FetchOptions,
+    verbose: bool,
+) -> Result<Vec<parser::Post>, Failure> {
+    let json = output.format == OutputFormat::Json;
+
+    if verbose && !quiet() {
+        // Keep stdout clean for JSON consumers
+        if json {
+            eprintln!("Creating feed...");
+        } else {
+            println!("{}", "Creating feed...".bright_black());
+        }
     }
     
     let feed = if user_only {
         feed::Feed::create_user_feed(user_profile, user_posts)
     } else {
-        match feed::Feed::create_combined_feed(user_profile, user_posts).await {
-            Ok(feed) => feed,
-            Err(e) => {
-                eprintln!("{} {}", "Warning:".yellow().bold(), format!("Failed to fetch remote feeds: {e}").red());
-                println!("{}", "Showing user posts only...".yellow());
-                let user_posts = parser::parse_file(&std::fs::read_to_string("social.org").unwrap_or_default(), None).1;
-                feed::Feed::create_user_feed(user_profile, user_posts)
-            }
-        }
+        load_combined_feed(user_profile, user_posts, fetch_options, verbose).await?
     };
     
-    let mut posts_to_show: Vec<&parser::Post> = feed.posts.iter().collect();
+    let mut posts_to_show: Vec<&parser::Post> = feed.posts
+        .iter()
+        .filter(|post| filters.matches(post))
+        .collect();
     
-    // Apply source filter
-    if let Some(source) = &source_filter {
-        posts_to_show.retain(|post| {
-                post.source().as_ref().map(|s| s == source).unwrap_or(false)
-            });
+    sort_posts(&mut posts_to_show, output.sort);
+
+    // Skip to the requested page and take only the requested count
+    posts_to_show.drain(..output.offset.min(posts_to_show.len()));
+    posts_to_show.truncate(output.count);
+
+    Ok(posts_to_show.into_iter().cloned().collect())
+}
+
+/// Keep printing the feed, only showing posts that weren't shown before, until Ctrl+C is pressed
+async fn handle_feed_watch(
+    config: &crate::config::Config,
+    user_profile: &parser::Profile,
+    user_only: bool,
+    filters: &PostFilters,
+    output: &FeedOutput,
+    interval: u64,
+    verbose: bool,
+) -> Result<(), Failure> {
+    if !std::io::stdout().is_terminal() {
+        eprintln!("{} {}", "Error:".red().bold(), "--watch needs a terminal, run the feed command without it when piping".red());
+        return Err(Failure::Usage);
     }
-    
-    // Apply days filter
-    if let Some(days) = days_filter {
-        let cutoff = Utc::now() - Duration::try_days(days as i64).unwrap_or_default();
-        posts_to_show.retain(|post| {
-                if let Some(post_time) = post.time() {
-                    post_time.naive_utc() > cutoff.naive_utc()
-                } else {
-                    false
-                }
-            });
+
+    let json = output.format == OutputFormat::Json;
+    // Every update fetches the feeds again, the cache only covers failed fetches
+    let fetch_options = remote::FetchOptions::from_config(config).always_fetch();
+    let mut seen_ids: HashSet<String> = HashSet::new();
+    let mut first_update = true;
+
+    // Clear the screen and start at the top
+    print!("\x1B[2J\x1B[H");
+
+    loop {
+        // Re-read the social file, so the user's own new posts show up too
+        let (current_profile, user_posts) = match std::fs::read_to_string(&config.social_file) {
+            Ok(content) => parser::parse_file(&content, Some(config.social_file.to_string_lossy().to_string())),
+            Err(e) => {
+                eprintln!("{} {}", "Warning:".yellow().bold(), format!("Failed to read {}: {e}", config.social_file.display()).red());
+                (user_profile.clone(), Vec::new())
+            }
+        };
+
+        let posts = tokio::select! {
+            posts = load_feed_posts(&current_profile, user_posts, user_only, filters, output, &fetch_options, verbose) => posts,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        // Failing feeds were reported, they may be back by the next update
+        let posts = posts.unwrap_or_default();
+        let new_posts: Vec<&parser::Post> = posts
+            .iter()
+            .filter(|post| !seen_ids.contains(&post.full_id()))
+            .collect();
+
+        if json {
+            if !new_posts.is_empty() {
+                println!("{}", formatting::posts_to_json(&new_posts));
+            }
+        } else if first_update {
+            print_decoration("=== Feed ===".cyan().bold());
+            print_decoration(format!("Showing {} posts, updating every {interval}s (Ctrl+C to stop)", new_posts.len()).bright_black());
+            print_posts(&new_posts, &current_profile, output.timezone);
+        } else if !new_posts.is_empty() {
+            println!();
+            let time = chrono::Local::now().format("%H:%M:%S");
+            print_decoration(format!("=== {} new posts at {time} ===", new_posts.len()).cyan().bold());
+            print_posts(&new_posts, &current_profile, output.timezone);
+        }
+
+        seen_ids.extend(new_posts.iter().map(|post| post.full_id()));
+        first_update = false;
+
+        tokio::select! {
+            _ = tokio::time::sleep(std::time::Duration::from_secs(interval)) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
     }
-    
-    // Take only the requested count
-    posts_to_show.truncate(count);
-    
-    println!("{}", "=== Feed ===".cyan().bold());
-    println!("{}", format!("Showing {} posts", posts_to_show.len()).bright_black());
-    for (i, post) in posts_to_show.iter().enumerate() {
-        println!("{}", formatting::format_post_colored(post, Some(user_profile)));
-        if i < posts_to_show.len() - 1 {
+
+    println!();
+    print_decoration("Stopped watching".bright_black());
+    Ok(())
+}
+
+/// Create the combined feed, reporting feeds that couldn't be fetched on stderr.
+/// Fails when every followed feed failed, a feed of only the user's posts isn't what was asked for.
+async fn load_combined_feed(
+    user_profile: &parser::Profile,
+    user_posts: Vec<parser::Post>,
+    fetch_options: &remote::FetchOptions,
+    verbose: bool,
+) -> Result<feed::Feed, Failure> {
+    let follow_count = user_profile.follow().as_ref().map_or(0, Vec::len);
+    let remote::CombinedFeed { feed, failures, .. } =
+        remote::create_combined_feed(user_profile, user_posts, fetch_options).await;
+
+    if verbose {
+        for failure in &failures {
+            eprintln!("{} {}", "Warning:".yellow().bold(),
+                format!("Failed to fetch feed for {} at {}: {}", failure.name, failure.url, failure.error).red());
+        }
+    } else if !failures.is_empty() {
+        eprintln!("{} {}", "Warning:".yellow().bold(),
+            format!("{} of the followed feeds could not be fetched, enable verbose output for details", failures.len()).red());
+    }
+
+    if follow_count > 0 && failures.len() >= follow_count {
+        eprintln!("{} {}", "Error:".red().bold(), "None of the followed feeds could be fetched".red());
+        return Err(Failure::FetchFailed);
+    }
+    Ok(feed)
+}
+
+async fn handle_search_command(
+    config: &crate::config::Config,
+    user_profile: &parser::Profile,
+    user_posts: Vec<parser::Post>,
+    query: &str,
+    use_regex: bool,
+    filters: &PostFilters,
+    verbose: bool,
+) -> Result<(), Failure> {
+    let matcher = if use_regex {
+        search::PostMatcher::regex(query)
+    } else {
+        search::PostMatcher::substring(query)
+    };
+    let matcher = match matcher {
+        Ok(matcher) => matcher,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Invalid search pattern: {e}").red());
+            return Err(Failure::Usage);
+        }
+    };
+
+    if verbose {
+        print_decoration("Creating feed...".bright_black());
+    }
+
+    let fetch_options = remote::FetchOptions::from_config(config);
+    let feed = load_combined_feed(user_profile, user_posts, &fetch_options, verbose).await?;
+
+    let matches: Vec<&parser::Post> = feed.posts
+        .iter()
+        .filter(|post| filters.matches(post) && matcher.matches_post(post))
+        .collect();
+
+    print_decoration("=== Search ===".cyan().bold());
+    print_decoration(format!("Found {} posts matching \"{}\"", matches.len(), query).bright_black());
+    for (i, post) in matches.iter().enumerate() {
+        println!("{}", formatting::format_post_colored_with_matches(post, Some(user_profile), Some(&matcher), config.timezone));
+        if i < matches.len() - 1 {
             println!();
         }
     }
+
+    // No hits is reported through the exit code, so the command composes in pipelines
+    if matches.is_empty() {
+        return Err(Failure::NoMatches);
+    }
+    Ok(())
+}
+
+/// Sort posts in the given order, posts without a time always go last
+fn sort_posts(posts: &mut Vec<&parser::Post>, order: SortOrder) {
+    let newest_first = |a: &parser::Post, b: &parser::Post| match (a.time(), b.time()) {
+        (Some(time_a), Some(time_b)) => time_b.cmp(&time_a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    };
+
+    match order {
+        SortOrder::Newest => posts.sort_by(|a, b| newest_first(a, b)),
+        SortOrder::Oldest => posts.sort_by(|a, b| match (a.time(), b.time()) {
+            (Some(time_a), Some(time_b)) => time_a.cmp(&time_b),
+            _ => newest_first(a, b),
+        }),
+        SortOrder::Author => posts.sort_by(|a, b| {
+            let author_a = a.author().as_deref().map(str::to_lowercase);
+            let author_b = b.author().as_deref().map(str::to_lowercase);
+            match (author_a, author_b) {
+                (Some(author_a), Some(author_b)) => author_a.cmp(&author_b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }
+            .then_with(|| newest_first(a, b))
+        }),
+    }
 }
 
-fn handle_profile_command(user_profile: &parser::Profile) {
-    println!("{}", "=== Profile ===".cyan().bold());
+async fn handle_profile_command(user_profile: &parser::Profile, image: bool, fetch_options: &remote::FetchOptions) {
+    print_decoration("=== Profile ===".cyan().bold());
     println!("{}", formatting::format_profile_colored(user_profile));
+
+    let Some(avatar_url) = user_profile.avatar().filter(|_| image) else {
+        return;
+    };
+    // The avatar URL printed above is all unsupported terminals get
+    let Some(protocol) = avatar::ImageProtocol::detect() else {
+        eprintln!("{}", "This terminal can't show images, see the avatar URL above".dimmed());
+        return;
+    };
+    match remote::fetch_bytes(avatar_url, fetch_options.timeout).await {
+        Ok(bytes) => match avatar::image_escape(protocol, &bytes) {
+            Some(escape) => println!("{escape}"),
+            None => eprintln!("{}", "The avatar's image format can't be shown in this terminal".dimmed()),
+        },
+        Err(e) => eprintln!("{} Failed to fetch the avatar: {e}", "Warning:".yellow().bold()),
+    }
 }
 
 fn handle_following_command(user_profile: &parser::Profile) {
-    println!("{}", "=== Following ===".cyan().bold());
+    print_decoration("=== Following ===".cyan().bold());
     match user_profile.follow() {
         Some(follows) => {
             if follows.is_empty() {
@@ -214,43 +783,569 @@ fn handle_following_command(user_profile: &parser::Profile) {
     }
 }
 
+/// Fetch every followed feed concurrently and print how each one answered, failing when any is dead
+async fn handle_check_following_command(user_profile: &parser::Profile, timeout: std::time::Duration) -> Result<(), Failure> {
+    print_decoration("=== Checking Follows ===".cyan().bold());
+    let follows = user_profile.follow().clone().unwrap_or_default();
+    if follows.is_empty() {
+        println!("{}", "Not following anyone yet.".yellow());
+        return Ok(());
+    }
+
+    let handles: Vec<_> = follows
+        .into_iter()
+        .map(|(name, url)| {
+            tokio::spawn(async move {
+                let check = remote::check_feed(&url, timeout).await;
+                (name, url, check)
+            })
+        })
+        .collect();
+
+    let mut dead = 0;
+    for handle in handles {
+        let Ok((name, url, check)) = handle.await else {
+            continue;
+        };
+        let (mark, detail) = match check {
+            remote::FeedCheck::Answered { status, moved_to, posts } if status.is_success() => {
+                let moved = moved_to.map(|moved_to| format!(", moved to {moved_to}")).unwrap_or_default();
+                match posts {
+                    Some(posts) if moved.is_empty() => ("✓".green().bold(), format!("{status}, {posts} posts").normal()),
+                    Some(posts) => ("!".yellow().bold(), format!("{status}, {posts} posts{moved}").yellow()),
+                    None => ("!".yellow().bold(), format!("{status}, not a social file{moved}").yellow()),
+                }
+            }
+            remote::FeedCheck::Answered { status, .. } if status == reqwest::StatusCode::NOT_FOUND => {
+                dead += 1;
+                ("✗".red().bold(), format!("{status}, the feed is gone").red())
+            }
+            remote::FeedCheck::Answered { status, .. } => {
+                dead += 1;
+                ("✗".red().bold(), format!("HTTP error {status}").red())
+            }
+            remote::FeedCheck::Unreachable(e) => {
+                dead += 1;
+                ("✗".red().bold(), format!("Connection failed: {e}").red())
+            }
+        };
+        println!("{mark} {} {} - {detail}", name.green().bold(), url.blue().underline());
+    }
+
+    if dead == 0 {
+        Ok(())
+    } else {
+        print_decoration(format!("{dead} followed feeds are dead, consider unfollowing them").red());
+        Err(Failure::Error)
+    }
+}
+
+fn handle_export_opml_command(user_profile: &parser::Profile, path: &Path) -> Result<(), Failure> {
+    let follows = user_profile.follow().clone().unwrap_or_default();
+    let title = if user_profile.nick().is_empty() {
+        "Org-social follows".to_string()
+    } else {
+        format!("{}'s org-social follows", user_profile.nick())
+    };
+
+    match std::fs::write(path, opml::follows_to_opml(&follows, &title)) {
+        Ok(_) => {
+            println!("{}", format!("Exported {} followed users to {}", follows.len(), path.display()).green());
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to write {}: {e}", path.display()).red());
+            Err(Failure::Error)
+        }
+    }
+}
+
+fn handle_import_opml_command(file_path: &Path, user_profile: &parser::Profile, opml_path: &Path) -> Result<(), Failure> {
+    let fail = |message: String| {
+        eprintln!("{} {}", "Error:".red().bold(), message.red());
+        Failure::Error
+    };
+
+    let opml_content = std::fs::read_to_string(opml_path)
+        .map_err(|e| fail(format!("Failed to read {}: {e}", opml_path.display())))?;
+    let imported = opml::parse_opml(&opml_content)
+        .map_err(|e| fail(format!("Invalid OPML in {}: {e}", opml_path.display())))?;
+
+    let mut follows = user_profile.follow().clone().unwrap_or_default();
+    let mut new_lines = Vec::new();
+    let mut skipped = 0;
+    for (nick, url) in imported {
+        if follows::is_followed(&follows, &url) {
+            skipped += 1;
+            continue;
+        }
+        new_lines.push(follows::follow_line(&nick, &url));
+        follows.push((nick, url));
+    }
+
+    if !new_lines.is_empty() {
+        let content = std::fs::read_to_string(file_path)
+            .map_err(|e| fail(format!("Failed to read {}: {e}", file_path.display())))?;
+        follows::write_social_file(file_path, &follows::add_follow_lines(&content, &new_lines))
+            .map_err(|e| fail(format!("Failed to write {}: {e}", file_path.display())))?;
+    }
+
+    println!("{} {}", "Added:".green(), format!("{} users", new_lines.len()).yellow().bold());
+    println!("{} {}", "Skipped, already followed:".green(), format!("{skipped} users").yellow().bold());
+    Ok(())
+}
+
+async fn handle_follow_command(
+    file_path: &Path,
+    user_profile: &parser::Profile,
+    nick: &str,
+    url: &str,
+    timeout: std::time::Duration,
+) -> Result<(), Failure> {
+    let fail = |message: String| {
+        eprintln!("{} {}", "Error:".red().bold(), message.red());
+        Failure::Error
+    };
+
+    let valid = reqwest::Url::parse(url.trim())
+        .is_ok_and(|parsed| matches!(parsed.scheme(), "http" | "https") && parsed.has_host());
+    if !valid {
+        eprintln!("{} {}", "Error:".red().bold(), format!("'{url}' isn't an http(s) URL").red());
+        return Err(Failure::Usage);
+    }
+    let follows = user_profile.follow().clone().unwrap_or_default();
+    if follows::is_followed(&follows, url) {
+        println!("{}", format!("Already following {url}, nothing was changed").yellow());
+        return Ok(());
+    }
+
+    // Feeds can be down for a while, they are followed anyway
+    if let Err(e) = remote::check_reachable(url.trim(), timeout).await {
+        eprintln!("{} {}", "Warning:".yellow().bold(), format!("{url} isn't reachable right now: {e}").yellow());
+    }
+
+    let line = follows::follow_line(nick, url);
+    let content = std::fs::read_to_string(file_path)
+        .map_err(|e| fail(format!("Failed to read {}: {e}", file_path.display())))?;
+    follows::write_social_file(file_path, &follows::add_follow_lines(&content, std::slice::from_ref(&line)))
+        .map_err(|e| fail(format!("Failed to write {}: {e}", file_path.display())))?;
+    println!("{} {}", "Added:".green(), line.yellow());
+    Ok(())
+}
+
+fn handle_unfollow_command(file_path: &Path, target: &str) -> Result<(), Failure> {
+    let fail = |message: String| {
+        eprintln!("{} {}", "Error:".red().bold(), message.red());
+        Failure::Error
+    };
+
+    let content = std::fs::read_to_string(file_path)
+        .map_err(|e| fail(format!("Failed to read {}: {e}", file_path.display())))?;
+    let (new_content, removed) = follows::remove_follow_lines(&content, target);
+    if removed.is_empty() {
+        println!("{}", format!("Not following '{target}', nothing was changed").yellow());
+        return Err(Failure::NoMatches);
+    }
+
+    follows::write_social_file(file_path, &new_content)
+        .map_err(|e| fail(format!("Failed to write {}: {e}", file_path.display())))?;
+    for line in removed {
+        println!("{} {}", "Removed:".green(), line.yellow());
+    }
+    Ok(())
+}
+
+fn handle_mute_command(user_profile: &parser::Profile, target: Option<&str>) -> Result<(), Failure> {
+    let follows = user_profile.follow().clone().unwrap_or_default();
+    let mut muted = mutes::load();
+    let Some(target) = target else {
+        print_decoration("=== Muted ===".cyan().bold());
+        if muted.is_empty() {
+            println!("{}", "Nobody is muted.".yellow());
+        }
+        let mut muted: Vec<&String> = muted.iter().collect();
+        muted.sort();
+        for url in muted {
+            match follows.iter().find(|(_, followed)| follows::normalize_url(followed) == url) {
+                Some((nick, _)) => println!("{} - {}", nick.green().bold(), url.blue().underline()),
+                None => println!("{}", url.blue().underline()),
+            }
+        }
+        return Ok(());
+    };
+
+    let Some(key) = mutes::resolve_target(&follows, target) else {
+        eprintln!("{} {}", "Error:".red().bold(), format!("Not following '{target}', pass the URL of their feed instead").red());
+        return Err(Failure::Usage);
+    };
+    if !muted.insert(key.clone()) {
+        println!("{}", format!("{key} is already muted").yellow());
+        return Ok(());
+    }
+    if let Err(e) = mutes::save(&muted) {
+        eprintln!("{} {}", "Error:".red().bold(), format!("Failed to save muted users: {e}").red());
+        return Err(Failure::Error);
+    }
+    println!("{} {}", "Muted:".green(), key.yellow());
+    Ok(())
+}
+
+fn handle_unmute_command(user_profile: &parser::Profile, target: &str) -> Result<(), Failure> {
+    let follows = user_profile.follow().clone().unwrap_or_default();
+    let mut muted = mutes::load();
+    // Nicks of posts without a source are muted as they are
+    let key = mutes::resolve_target(&follows, target).unwrap_or_else(|| target.trim().to_string());
+    if !muted.remove(&key) {
+        println!("{}", format!("'{target}' isn't muted, nothing was changed").yellow());
+        return Err(Failure::NoMatches);
+    }
+    if let Err(e) = mutes::save(&muted) {
+        eprintln!("{} {}", "Error:".red().bold(), format!("Failed to save muted users: {e}").red());
+        return Err(Failure::Error);
+    }
+    println!("{} {}", "Unmuted:".green(), key.yellow());
+    Ok(())
+}
+
 async fn handle_stats_command(
     user_profile: &parser::Profile,
     user_posts: &[parser::Post],
+    fetch_options: &remote::FetchOptions,
     verbose: bool,
 ) {
-    println!("{}", "=== Statistics ===".cyan().bold());
+    print_decoration("=== Statistics ===".cyan().bold());
     println!("{} {}", "User posts:".green(), user_posts.len().to_string().yellow().bold());
     
     if let Some(follows) = user_profile.follow() {
         println!("{} {}", "Following:".green(), format!("{} users", follows.len()).yellow().bold());
         
         if verbose {
-            println!("{}", "Fetching remote feed statistics...".bright_black());
-            let feeds = network::get_feeds_from_profile_with_timeout(user_profile).await;
-            let total_remote_posts: usize = feeds.iter().map(|(_, posts, _)| posts.len()).sum();
+            print_decoration("Fetching remote feed statistics...".bright_black());
+            let mut feeds = remote::fetch_feeds(follows.clone(), fetch_options).await;
+            let post_count = |fetched: &remote::FetchedFeed| {
+                fetched.result.as_ref().map_or(0, |(_, posts)| posts.len())
+            };
+            // Most active feeds first, failed feeds last
+            feeds.sort_by_key(|fetched| (fetched.result.is_err(), std::cmp::Reverse(post_count(fetched))));
+
+            println!();
+            println!("{}", "Posts per followed user:".green());
+            for fetched in &feeds {
+                // Prefer the nick the feed gives itself over the name it's followed under
+                let nick = match &fetched.result {
+                    Ok((profile, _)) if !profile.nick().is_empty() => profile.nick(),
+                    _ => fetched.name.as_str(),
+                };
+                let status = match &fetched.result {
+                    Ok((_, posts)) if posts.is_empty() => "no posts".yellow().bold(),
+                    Ok((_, posts)) => format!("{} posts", posts.len()).yellow().bold(),
+                    Err(e) => format!("failed: {e}").red().bold(),
+                };
+                println!("  {} - {} - {}", nick.green().bold(), fetched.url.blue().underline(), status);
+            }
+            println!();
+
+            let total_remote_posts: usize = feeds.iter().map(post_count).sum();
+            let dead_feeds = feeds.iter().filter(|fetched| post_count(fetched) == 0).count();
             println!("{} {}", "Total remote posts:".green(), total_remote_posts.to_string().yellow().bold());
+            // A post can come from more than one feed, the combined feed shows it once
+            let combined_posts: HashSet<String> = user_posts
+                .iter()
+                .chain(feeds.iter().filter_map(|fetched| fetched.result.as_ref().ok()).flat_map(|(_, posts)| posts))
+                .map(|post| post.full_id())
+                .collect();
             println!("{} {}", "Total posts in combined feed:".green(), 
-                (user_posts.len() + total_remote_posts).to_string().yellow().bold());
+                combined_posts.len().to_string().yellow().bold());
+            if dead_feeds > 0 {
+                println!("{} {}", "Feeds without posts or failing:".green(), dead_feeds.to_string().red().bold());
+            }
         }
     } else {
         println!("{} {}", "Following:".green(), "0 users".yellow().bold());
     }
 }
 
-async fn handle_tui_command(
-    file_path: &std::path::Path,
+/// Print the problems found in the social file, one `file:line: warning` per line
+fn handle_lint_command(file_path: &Path) -> Result<(), Failure> {
+    let content = match std::fs::read_to_string(file_path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to read {}: {e}", file_path.display()).red());
+            return Err(Failure::SocialFile);
+        }
+    };
+
+    let warnings = lint::lint(&content);
+    for warning in &warnings {
+        println!("{}:{}: {} {}", file_path.display(), warning.line, "warning:".yellow().bold(), warning.message);
+    }
+    if warnings.is_empty() {
+        print_decoration("No problems found".green());
+        Ok(())
+    } else {
+        print_decoration(format!("{} warnings", warnings.len()).yellow());
+        Err(Failure::Error)
+    }
+}
+
+/// Read content from a CLI argument, "-" means stdin
+fn read_content_arg(content: &str) -> std::io::Result<String> {
+    if content == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        Ok(buffer)
+    } else {
+        Ok(content.to_string())
+    }
+}
+
+/// Find the ID of the most recently appended post in the social file
+fn last_post_id(file_path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(file_path).ok()?;
+    let (_, posts) = parser::parse_file(&content, None);
+    posts.last().map(|post| post.id().to_string())
+}
+
+fn handle_post_command(
+    file_path: &Path,
+    content: &str,
+    tags: &[String],
+    mood: Option<&str>,
+    lang: Option<&str>,
+) -> Result<(), Failure> {
+    let content = match read_content_arg(content) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to read content from stdin: {e}").red());
+            return Err(Failure::Error);
+        }
+    };
+
+    let mut new_post_state = new_post::NewPostState::new(None);
+    new_post_state.content = content.trim_end().to_string();
+    new_post_state.tags_input = tags.join(" ");
+    new_post_state.finalize_tags_input();
+    new_post_state.mood = mood.unwrap_or_default().to_string();
+    new_post_state.lang = lang.unwrap_or_default().to_string();
+
+    // Same guard the TUI uses before submitting
+    if !new_post_state.is_ready_to_submit() {
+        eprintln!("{} {}", "Error:".red().bold(), "Refusing to write an empty post".red());
+        return Err(Failure::Usage);
+    }
+
+    match compose::save_new_post(file_path, &new_post_state) {
+        Ok(_) => {
+            match last_post_id(file_path) {
+                Some(id) => println!("{id}"),
+                None => println!("{}", format!("New post saved to {}", file_path.display()).green()),
+            }
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to save post: {e}").red());
+            Err(Failure::Error)
+        }
+    }
+}
+
+/// Find the post matching the target, either by its exact full ID or by a unique partial match
+fn resolve_target_post<'a>(posts: &'a [parser::Post], target: &str) -> Result<&'a parser::Post, Vec<&'a parser::Post>> {
+    if let Some(post) = posts.iter().find(|post| post.full_id() == target) {
+        return Ok(post);
+    }
+
+    let candidates: Vec<&parser::Post> = posts
+        .iter()
+        .filter(|post| post.full_id().contains(target))
+        .collect();
+
+    match candidates.as_slice() {
+        [post] => Ok(post),
+        _ => Err(candidates),
+    }
+}
+
+async fn handle_reply_command(
+    config: &crate::config::Config,
+    user_profile: &parser::Profile,
+    user_posts: Vec<parser::Post>,
+    target: &str,
+    content: &str,
+    tags: &[String],
+    mood: Option<&str>,
+) -> Result<(), Failure> {
+    let content = match read_content_arg(content) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to read content from stdin: {e}").red());
+            return Err(Failure::Error);
+        }
+    };
+
+    let feed = load_combined_feed(user_profile, user_posts, &remote::FetchOptions::from_config(config), false).await?;
+    let target_post = match resolve_target_post(&feed.posts, target) {
+        Ok(post) => post,
+        Err(candidates) if candidates.is_empty() => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("No post found matching \"{target}\"").red());
+            return Err(Failure::Usage);
+        }
+        Err(candidates) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("\"{target}\" is ambiguous, candidates:").red());
+            for post in candidates {
+                eprintln!("  {}", post.full_id());
+            }
+            return Err(Failure::Usage);
+        }
+    };
+
+    // Tags are split the same way the TUI does it
+    let mut reply_state = reply::ReplyState::new(target_post.full_id(), None);
+    reply_state.content = content.trim_end().to_string();
+    reply_state.tags_input = tags.join(" ");
+    reply_state.finalize_tags_input();
+    reply_state.mood = mood.unwrap_or_default().to_string();
+
+    // Same guard the TUI uses before submitting
+    if !reply_state.is_ready_to_submit() {
+        eprintln!("{} {}", "Error:".red().bold(), "Refusing to write an empty reply".red());
+        return Err(Failure::Usage);
+    }
+
+    let file_path = config.social_file.as_path();
+    match compose::save_reply(file_path, &reply_state) {
+        Ok(_) => {
+            match last_post_id(file_path) {
+                Some(id) => println!("{id}"),
+                None => println!("{}", format!("Reply saved to {}", file_path.display()).green()),
+            }
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to save reply: {e}").red());
+            Err(Failure::Error)
+        }
+    }
+}
+
+async fn handle_export_command(
+    config: &crate::config::Config,
     user_profile: &parser::Profile,
     user_posts: Vec<parser::Post>,
+    format: ExportFormat,
+    output: Option<&Path>,
     user_only: bool,
-    source_filter: Option<String>,
-    days_filter: Option<u32>,
-) {
-    match tui::run_tui(file_path, user_profile, user_posts, user_only, source_filter, days_filter).await {
-        Ok(_) => {}
+    verbose: bool,
+) -> Result<(), Failure> {
+    let feed = if user_only {
+        feed::Feed::create_user_feed(user_profile, user_posts)
+    } else {
+        load_combined_feed(user_profile, user_posts, &remote::FetchOptions::from_config(config), verbose).await?
+    };
+    let posts: Vec<&parser::Post> = feed.posts.iter().collect();
+
+    let title = if user_profile.nick().is_empty() {
+        "Org-social feed".to_string()
+    } else {
+        format!("{}'s org-social feed", user_profile.nick())
+    };
+    let document = match format {
+        ExportFormat::Html => export::posts_to_html(&posts, &title, config.timezone),
+        ExportFormat::Markdown => export::posts_to_markdown(&posts, &title, config.timezone),
+    };
+
+    let Some(output) = output else {
+        print!("{document}");
+        return Ok(());
+    };
+    match std::fs::write(output, document) {
+        Ok(_) => {
+            println!("{}", format!("Exported {} posts to {}", posts.len(), output.display()).green());
+            Ok(())
+        }
         Err(e) => {
-            eprintln!("{} {}", "Error running TUI:".red().bold(), e);
-            std::process::exit(1);
+            eprintln!("{} {}", "Error:".red().bold(), format!("Failed to write {}: {e}", output.display()).red());
+            Err(Failure::Error)
         }
     }
 }
+
+async fn handle_tui_command(
+    config: &crate::config::Config,
+    user_profile: &parser::Profile,
+    user_posts: Vec<parser::Post>,
+    user_only: bool,
+    filters: PostFilters,
+) -> Result<(), Failure> {
+    tui::run_tui(config, user_profile, user_posts, user_only, filters).await.map_err(|e| {
+        eprintln!("{} {}", "Error running TUI:".red().bold(), e);
+        Failure::Error
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(id: &str, author: Option<&str>) -> parser::Post {
+        let mut post = parser::Post::new(id.to_string(), String::new());
+        if let Some(author) = author {
+            post.set_author(author.to_string());
+        }
+        post
+    }
+
+    fn posts() -> Vec<parser::Post> {
+        vec![
+            post("2025-01-02T10:00:00+0000", Some("bob")),
+            post("no-time", Some("Alice")),
+            post("2025-01-01T10:00:00+0000", Some("alice")),
+            post("2025-01-03T10:00:00+0000", None),
+        ]
+    }
+
+    fn sorted_ids(posts: &[parser::Post], order: SortOrder) -> Vec<&str> {
+        let mut posts: Vec<&parser::Post> = posts.iter().collect();
+        sort_posts(&mut posts, order);
+        posts.iter().map(|post| post.id()).collect()
+    }
+
+    #[test]
+    fn sort_newest_puts_posts_without_time_last() {
+        assert_eq!(
+            sorted_ids(&posts(), SortOrder::Newest),
+            vec!["2025-01-03T10:00:00+0000", "2025-01-02T10:00:00+0000", "2025-01-01T10:00:00+0000", "no-time"]
+        );
+    }
+
+    #[test]
+    fn sort_oldest_puts_posts_without_time_last() {
+        assert_eq!(
+            sorted_ids(&posts(), SortOrder::Oldest),
+            vec!["2025-01-01T10:00:00+0000", "2025-01-02T10:00:00+0000", "2025-01-03T10:00:00+0000", "no-time"]
+        );
+    }
+
+    #[test]
+    fn sort_author_ignores_case_and_puts_newest_first_per_author() {
+        assert_eq!(
+            sorted_ids(&posts(), SortOrder::Author),
+            vec!["2025-01-01T10:00:00+0000", "no-time", "2025-01-02T10:00:00+0000", "2025-01-03T10:00:00+0000"]
+        );
+    }
+
+    #[test]
+    fn unfollow_of_a_user_not_followed_is_no_match() {
+        let path = std::env::temp_dir().join(format!("org-social-rs-{}-unfollow.org", std::process::id()));
+        let content = "#+TITLE: Test\n#+NICK: test\n#+FOLLOW: bob https://bob.example/social.org\n\n* Posts\n";
+        std::fs::write(&path, content).unwrap();
+
+        let result = handle_unfollow_command(&path, "alice");
+        let unchanged = std::fs::read_to_string(&path).unwrap() == content;
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(Failure::NoMatches)));
+        assert_eq!(ExitCode::from(Failure::NoMatches), ExitCode::from(5));
+        assert!(unchanged);
+    }
+}
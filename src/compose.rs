@@ -0,0 +1,85 @@
+//! Appending new posts and replies to the social file, shared by the CLI and the TUI.
+//!
+//! The library's `NewPostManager::save_new_post` and `ReplyManager::save_reply` cut the preview
+//! in their status message at 50 bytes, panicking after the post was written when that isn't a
+//! char boundary. Posts are built by the library and appended here, with a char-safe preview.
+
+use crate::formatting;
+use org_social_lib_rs::new_post::NewPostState;
+use org_social_lib_rs::reply::ReplyState;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+/// Characters of the content shown in the status message after saving
+const PREVIEW_CHARS: usize = 50;
+
+/// Append the new post to the social file, returning a status message with a preview of it
+pub fn save_new_post(file_path: &Path, state: &NewPostState) -> Result<String, Box<dyn std::error::Error>> {
+    append(file_path, &state.create_new_post()?)?;
+    Ok(format!("New post saved to {}: {}", file_path.display(), preview(&state.content)))
+}
+
+/// Append the reply to the social file, returning a status message with a preview of it
+pub fn save_reply(file_path: &Path, state: &ReplyState) -> Result<String, Box<dyn std::error::Error>> {
+    append(file_path, &state.create_reply_post()?)?;
+    Ok(format!("Reply saved to {}: {}", file_path.display(), preview(&state.content)))
+}
+
+fn append(file_path: &Path, post_text: &str) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(file_path)?;
+    writeln!(file, "{post_text}")
+}
+
+fn preview(content: &str) -> String {
+    formatting::truncate_chars(content, PREVIEW_CHARS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("org-social-rs-{}-{name}.org", std::process::id()));
+        std::fs::write(&path, "#+TITLE: Test\n#+NICK: test\n\n* Posts\n").unwrap();
+        path
+    }
+
+    #[test]
+    fn saves_multibyte_post_longer_than_preview() {
+        let path = temp_file("multibyte-post");
+        let content = format!("a{}", "é".repeat(30));
+        let mut state = NewPostState::new(None);
+        state.content = content.clone();
+
+        let message = save_new_post(&path, &state).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // 61 bytes but 31 characters, the whole content is previewed
+        assert!(written.contains(&content));
+        assert!(message.ends_with(&content));
+    }
+
+    #[test]
+    fn saves_multibyte_reply_longer_than_preview() {
+        let path = temp_file("multibyte-reply");
+        let content = format!("ü{}", "ñ".repeat(40));
+        let mut state = ReplyState::new("https://example.com/social.org#2025-01-01T10:00:00+0100".to_string(), None);
+        state.content = content.clone();
+
+        let message = save_reply(&path, &state).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(written.contains(&content));
+        assert!(written.contains(":REPLY_TO: https://example.com/social.org#2025-01-01T10:00:00+0100"));
+        assert!(message.ends_with(&content));
+    }
+
+    #[test]
+    fn preview_cuts_multibyte_content_at_characters() {
+        assert_eq!(preview(&format!("a{}", "é".repeat(60))), format!("a{}…", "é".repeat(48)));
+    }
+}
@@ -1,7 +1,16 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 use crate::cli;
+use crate::formatting::DisplayTimezone;
+
+/// Name of the config file used without a profile
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// Environment variable overriding the social file for a single run
+const SOCIAL_FILE_ENV: &str = "ORG_SOCIAL_FILE";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -9,6 +18,131 @@ pub struct Config {
     pub social_file: PathBuf,
     /// Default number of posts to show in feed
     pub default_feed_count: usize,
+    /// Show post times relative to now (e.g. "3h") instead of absolute dates in the TUI
+    pub relative_timestamps: bool,
+    /// Time zone post times are shown in: local, original (as written), utc or an offset like +02:00.
+    /// Names like Europe/Paris are not supported, as no time zone database is bundled.
+    #[serde(default = "default_display_timezone")]
+    pub display_timezone: String,
+    /// `display_timezone` parsed by `merge_with_cli`, local time when it is unknown
+    #[serde(skip)]
+    pub timezone: DisplayTimezone,
+    /// Seconds for which cached remote feeds are used without fetching them again
+    #[serde(default = "default_cache_ttl_seconds")]
+    pub cache_ttl_seconds: u64,
+    /// Seconds after which fetching a single remote feed is abandoned
+    #[serde(default = "default_network_timeout_seconds")]
+    pub network_timeout_seconds: u64,
+    /// Maximum number of remote feeds fetched at the same time
+    #[serde(default = "default_max_concurrent_fetches")]
+    pub max_concurrent_fetches: usize,
+    /// Soft limit on the length of posts and replies written in the TUI, the counter turns red above it, zero disables it
+    #[serde(default = "default_post_char_limit")]
+    pub post_char_limit: usize,
+    /// Show a desktop notification when refreshing the TUI finds new mentions or replies
+    #[serde(default)]
+    pub desktop_notifications: bool,
+    /// Ring the terminal bell when refreshing the TUI finds new mentions or replies
+    #[serde(default)]
+    pub bell_on_notification: bool,
+    /// Show the output of `feed` through `$PAGER` when printing to a terminal
+    #[serde(default)]
+    pub pager: bool,
+    /// Order of the TUI's threaded view: activity (latest post first) or root_time
+    #[serde(default = "default_thread_sort")]
+    pub thread_sort: String,
+    /// View the TUI starts in: list, threaded, notifications, bookmarks or own
+    #[serde(default = "default_view_mode")]
+    pub default_view_mode: String,
+    /// Hide reactions and poll votes from the TUI's list and threaded views
+    #[serde(default)]
+    pub hide_reactions: bool,
+    /// Only load the most recent posts of the feed in the TUI, zero loads all of them
+    #[serde(default)]
+    pub tui_max_posts: usize,
+    /// Posts containing any of these terms in their content or tags are hidden from the TUI, ignoring case
+    #[serde(default)]
+    pub muted_keywords: Vec<String>,
+    /// Number the lines of expanded code blocks in the TUI
+    #[serde(default)]
+    pub code_line_numbers: bool,
+    /// Give each author their own color in the TUI's list and threaded views
+    #[serde(default)]
+    pub author_colors: bool,
+    /// Characters of each post's content shown in the TUI's list and threaded views
+    #[serde(default = "default_list_preview_length")]
+    pub list_preview_length: usize,
+    /// Percentage of the TUI's width (or height on narrow terminals) taken by the post list, from 10 to 90
+    #[serde(default = "default_list_pane_percent")]
+    pub list_pane_percent: u16,
+    /// TUI keybindings, mapping action names (e.g. `next_post`) to keys (e.g. `"C-n"`)
+    #[serde(default)]
+    pub keybindings: HashMap<String, String>,
+    /// TUI colors
+    #[serde(default)]
+    pub theme: ThemeConfig,
+}
+
+/// TUI colors for each role, as ratatui color names (e.g. `"lightblue"`) or `"#rrggbb"` hex
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub author: String,
+    pub timestamp: String,
+    pub link: String,
+    pub mention: String,
+    pub tag: String,
+    /// Background of the selected post in lists
+    pub selected: String,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            author: "green".to_string(),
+            timestamp: "blue".to_string(),
+            link: "blue".to_string(),
+            mention: "cyan".to_string(),
+            tag: "cyan".to_string(),
+            selected: "darkgray".to_string(),
+        }
+    }
+}
+
+fn default_cache_ttl_seconds() -> u64 {
+    300
+}
+
+fn default_network_timeout_seconds() -> u64 {
+    30
+}
+
+fn default_max_concurrent_fetches() -> usize {
+    8
+}
+
+fn default_post_char_limit() -> usize {
+    500
+}
+
+fn default_list_preview_length() -> usize {
+    25
+}
+
+fn default_list_pane_percent() -> u16 {
+    30
+}
+
+fn default_thread_sort() -> String {
+    "activity".to_string()
+}
+
+fn default_display_timezone() -> String {
+    "local".to_string()
+}
+
+fn default_view_mode() -> String {
+    "list".to_string()
 }
 
 impl Default for Config {
@@ -16,38 +150,88 @@ impl Default for Config {
         Self {
             social_file: PathBuf::from("social.org"),
             default_feed_count: 10,
+            relative_timestamps: true,
+            display_timezone: default_display_timezone(),
+            timezone: DisplayTimezone::default(),
+            cache_ttl_seconds: default_cache_ttl_seconds(),
+            network_timeout_seconds: default_network_timeout_seconds(),
+            max_concurrent_fetches: default_max_concurrent_fetches(),
+            post_char_limit: default_post_char_limit(),
+            desktop_notifications: false,
+            bell_on_notification: false,
+            pager: false,
+            thread_sort: default_thread_sort(),
+            default_view_mode: default_view_mode(),
+            hide_reactions: false,
+            muted_keywords: Vec::new(),
+            tui_max_posts: 0,
+            code_line_numbers: false,
+            author_colors: false,
+            list_preview_length: default_list_preview_length(),
+            list_pane_percent: default_list_pane_percent(),
+            keybindings: HashMap::new(),
+            theme: ThemeConfig::default(),
         }
     }
 }
 
 impl Config {
-    /// Load configuration from file or create default
-    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
-        let config_path = Self::get_config_path()?;
-        
+    /// Load configuration from file or create default, from the profile's file when a profile is given
+    pub fn load(profile: Option<&str>) -> Result<Self, Box<dyn std::error::Error>> {
+        let config_path = Self::get_config_path(profile)?;
+        if let Some(name) = profile {
+            if config_path.file_name() == Some(DEFAULT_CONFIG_FILE.as_ref()) {
+                eprintln!("Warning: No config-{name}.toml for profile '{name}', using {DEFAULT_CONFIG_FILE}");
+            }
+        }
+
+        Self::load_from(&config_path, std::env::vars().collect())
+    }
+
+    /// Build the configuration from the defaults, the config file and the environment variables
+    fn load_from(config_path: &Path, env: config::Map<String, String>) -> Result<Self, Box<dyn std::error::Error>> {
+        // `ORG_SOCIAL_FILE` is a shorter name for `ORG_SOCIAL_SOCIAL_FILE`, and wins over it
+        let social_file = env.get(SOCIAL_FILE_ENV).filter(|path| !path.is_empty()).cloned();
         let settings = config::Config::builder()
             .add_source(config::Config::try_from(&Config::default())?)
             .add_source(config::File::from(config_path).required(false))
-            .add_source(config::Environment::with_prefix("ORG_SOCIAL"))
+            .add_source(config::Environment::with_prefix("ORG_SOCIAL").source(Some(env)))
+            .set_override_option("social_file", social_file)?
             .build()?;
 
         Ok(settings.try_deserialize()?)
     }
 
-    /// Get the configuration file path
-    fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
-        let config_dir = dirs::config_dir()
-            .ok_or("Unable to determine config directory")?;
-        
-        let app_config_dir = config_dir.join("org-social-rs");
+    /// Get the application's configuration directory, creating it if needed
+    pub fn get_config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+        #[cfg(not(test))]
+        let app_config_dir = dirs::config_dir()
+            .ok_or("Unable to determine config directory")?
+            .join("org-social-rs");
+        // Tests never touch the user's config directory
+        #[cfg(test)]
+        let app_config_dir = crate::test_support::config_dir();
+
         std::fs::create_dir_all(&app_config_dir)?;
         
-        Ok(app_config_dir.join("config.toml"))
+        Ok(app_config_dir)
     }
 
-    /// Save the current configuration to file
+    /// Get the configuration file path, `config-<profile>.toml` if it exists for the given profile
+    pub fn get_config_path(profile: Option<&str>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let config_dir = Self::get_config_dir()?;
+        if let Some(name) = profile {
+            let profile_path = config_dir.join(format!("config-{name}.toml"));
+            if profile_path.exists() {
+                return Ok(profile_path);
+            }
+        }
+        Ok(config_dir.join(DEFAULT_CONFIG_FILE))
+    }
+
+    /// Save the current configuration to the default config file
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config_path = Self::get_config_path()?;
+        let config_path = Self::get_config_path(None)?;
         let toml_string = toml::to_string_pretty(self)?;
         std::fs::write(config_path, toml_string)?;
         Ok(())
@@ -55,7 +239,7 @@ impl Config {
 
     /// Create a default configuration file if it doesn't exist
     pub fn create_default_if_missing() -> Result<(), Box<dyn std::error::Error>> {
-        let config_path = Self::get_config_path()?;
+        let config_path = Self::get_config_path(None)?;
         
         if !config_path.exists() {
             let default_config = Config::default();
@@ -69,9 +253,126 @@ impl Config {
     /// Merge CLI options with config, CLI takes precedence
     pub fn merge_with_cli(&self, cli: &cli::Cli) -> Self {
         Self {
+            // `--file` wins over `ORG_SOCIAL_FILE`, which is already part of the loaded config
             social_file: cli.file_override().unwrap_or_else(|| self.social_file.clone()),
             // Keep other fields from config
             default_feed_count: self.default_feed_count,
+            relative_timestamps: self.relative_timestamps,
+            display_timezone: self.display_timezone.clone(),
+            timezone: DisplayTimezone::from_name(&self.display_timezone).unwrap_or_default(),
+            // Without the cache every feed is fetched again
+            cache_ttl_seconds: if cli.no_cache { 0 } else { self.cache_ttl_seconds },
+            network_timeout_seconds: self.network_timeout_seconds,
+            max_concurrent_fetches: self.max_concurrent_fetches,
+            post_char_limit: self.post_char_limit,
+            desktop_notifications: self.desktop_notifications,
+            bell_on_notification: self.bell_on_notification,
+            pager: self.pager,
+            thread_sort: self.thread_sort.clone(),
+            default_view_mode: cli.view_override().unwrap_or_else(|| self.default_view_mode.clone()),
+            hide_reactions: self.hide_reactions,
+            muted_keywords: self.muted_keywords.clone(),
+            // `tui --all` lifts the cap
+            tui_max_posts: if cli.all_posts() { 0 } else { self.tui_max_posts },
+            code_line_numbers: self.code_line_numbers,
+            author_colors: self.author_colors,
+            list_preview_length: self.list_preview_length,
+            list_pane_percent: self.list_pane_percent,
+            keybindings: self.keybindings.clone(),
+            theme: self.theme.clone(),
+        }
+    }
+}
+
+/// Minimal social file created for first-run users
+fn social_file_template(nick: &str) -> String {
+    format!("#+TITLE: {nick}'s social\n#+NICK: {nick}\n#+DESCRIPTION:\n\n* Posts\n")
+}
+
+/// Make sure the social file exists, offering to create it from a template when it doesn't.
+/// Only asks when run interactively, declining or a failed creation returns an error.
+pub fn ensure_social_file(path: &Path) -> io::Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    let missing = || io::Error::new(io::ErrorKind::NotFound, "the file doesn't exist");
+    if !io::stdin().is_terminal() {
+        return Err(missing());
+    }
+
+    eprint!("The social file {} doesn't exist. Create it? [Y/n] ", path.display());
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    if !matches!(answer.trim().to_lowercase().as_str(), "" | "y" | "yes") {
+        return Err(missing());
+    }
+
+    let nick = std::env::var("USER")
+        .ok()
+        .filter(|user| !user.is_empty() && !user.contains(char::is_whitespace))
+        .unwrap_or_else(|| "me".to_string());
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, social_file_template(&nick))?;
+    eprintln!("Created {}, set your nick and description there", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    const SOCIAL_FILE_VAR: &str = "ORG_SOCIAL_SOCIAL_FILE";
+
+    /// Config file setting the social file, removed when dropped
+    struct ConfigFile(PathBuf);
+
+    impl ConfigFile {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("org-social-rs-{}-{name}.toml", std::process::id()));
+            std::fs::write(&path, "social_file = \"from-config.org\"\n").unwrap();
+            Self(path)
         }
     }
+
+    impl Drop for ConfigFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn env(vars: &[(&str, &str)]) -> config::Map<String, String> {
+        vars.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    fn social_file(config_file: &ConfigFile, vars: &[(&str, &str)], args: &[&str]) -> PathBuf {
+        let config = Config::load_from(&config_file.0, env(vars)).unwrap();
+        let cli = cli::Cli::try_parse_from(std::iter::once("org-social-rs").chain(args.iter().copied())).unwrap();
+        config.merge_with_cli(&cli).social_file
+    }
+
+    #[test]
+    fn org_social_file_sets_the_social_file() {
+        let config_file = ConfigFile::new("env-file");
+        let config = Config::load_from(&config_file.0, env(&[(SOCIAL_FILE_ENV, "from-env.org")])).unwrap();
+        assert_eq!(config.social_file, PathBuf::from("from-env.org"));
+
+        // An empty variable is ignored
+        let config = Config::load_from(&config_file.0, env(&[(SOCIAL_FILE_ENV, "")])).unwrap();
+        assert_eq!(config.social_file, PathBuf::from("from-config.org"));
+    }
+
+    #[test]
+    fn social_file_precedence() {
+        let config_file = ConfigFile::new("precedence");
+        let both = [(SOCIAL_FILE_ENV, "short-env.org"), (SOCIAL_FILE_VAR, "long-env.org")];
+
+        assert_eq!(social_file(&config_file, &both, &["--file", "cli.org", "feed"]), PathBuf::from("cli.org"));
+        assert_eq!(social_file(&config_file, &both, &["feed"]), PathBuf::from("short-env.org"));
+        assert_eq!(social_file(&config_file, &both[1..], &["feed"]), PathBuf::from("long-env.org"));
+        assert_eq!(social_file(&config_file, &[], &["feed"]), PathBuf::from("from-config.org"));
+    }
 }
@@ -0,0 +1,208 @@
+//! The `doctor` command, checking the setup for the usual reasons no posts show up.
+
+use crate::cli::Failure;
+use crate::config::Config;
+use crate::{lint, remote};
+use colored::*;
+use org_social_lib_rs::parser;
+use std::time::Duration;
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    /// Works, but likely not what the user wants
+    Warn,
+    Fail,
+}
+
+struct Check {
+    status: Status,
+    name: String,
+    detail: String,
+    /// What to do about a warning or failure
+    fix: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self { status: Status::Pass, name: name.to_string(), detail: detail.into(), fix: None }
+    }
+
+    fn warn(name: &str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self { status: Status::Warn, name: name.to_string(), detail: detail.into(), fix: Some(fix.into()) }
+    }
+
+    fn fail(name: &str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self { status: Status::Fail, name: name.to_string(), detail: detail.into(), fix: Some(fix.into()) }
+    }
+
+    fn print(&self) {
+        let mark = match self.status {
+            Status::Pass => "✓".green().bold(),
+            Status::Warn => "!".yellow().bold(),
+            Status::Fail => "✗".red().bold(),
+        };
+        println!("{mark} {} {}", format!("{}:", self.name).bold(), self.detail);
+        if let Some(fix) = &self.fix {
+            println!("    {} {fix}", "Fix:".bright_black());
+        }
+    }
+}
+
+/// Run every check and print the checklist, failing when any check failed
+pub async fn run(config: &Config, profile: Option<&str>) -> Result<(), Failure> {
+    println!("{}", "=== Doctor ===".cyan().bold());
+    let mut checks = config_checks(profile);
+    let follows = match social_file_checks(config) {
+        Ok((file_checks, profile)) => {
+            checks.extend(file_checks);
+            profile.follow().clone().unwrap_or_default()
+        }
+        Err(check) => {
+            checks.push(check);
+            Vec::new()
+        }
+    };
+    for check in &checks {
+        check.print();
+    }
+
+    // Followed feeds are checked last, they take the longest
+    for check in follow_checks(follows, Duration::from_secs(config.network_timeout_seconds)).await {
+        check.print();
+        checks.push(check);
+    }
+
+    let failed = checks.iter().filter(|check| check.status == Status::Fail).count();
+    println!();
+    if failed == 0 {
+        println!("{}", "No problems found".green());
+        Ok(())
+    } else {
+        println!("{}", format!("{failed} of {} checks failed", checks.len()).red());
+        Err(Failure::Error)
+    }
+}
+
+/// The config file parses and the config directory is writable
+fn config_checks(profile: Option<&str>) -> Vec<Check> {
+    let mut checks = Vec::new();
+    let path = match Config::get_config_path(profile) {
+        Ok(path) => path,
+        Err(e) => {
+            checks.push(Check::fail("Config", e.to_string(), "Make sure your home directory is set and accessible"));
+            return checks;
+        }
+    };
+
+    checks.push(match Config::load(profile) {
+        Ok(_) if path.exists() => Check::pass("Config", path.display().to_string()),
+        Ok(_) => Check::pass("Config", format!("{} doesn't exist, using the defaults", path.display())),
+        Err(e) => Check::fail(
+            "Config",
+            format!("{}: {e}", path.display()),
+            "Fix the file, or delete it to have a default one created",
+        ),
+    });
+
+    // Drafts, bookmarks and the feed cache are all written there
+    if let Some(dir) = path.parent() {
+        let probe = dir.join(".doctor-write-test");
+        checks.push(match std::fs::write(&probe, "") {
+            Ok(_) => {
+                let _ = std::fs::remove_file(&probe);
+                Check::pass("Config directory", format!("{} is writable", dir.display()))
+            }
+            Err(e) => Check::fail(
+                "Config directory",
+                format!("{} isn't writable: {e}", dir.display()),
+                "Fix the permissions of the directory, drafts, bookmarks and cached feeds are kept there",
+            ),
+        });
+    }
+    checks
+}
+
+/// The social file exists, has a posts section and a complete profile.
+/// Returns the checks with the parsed profile, or the failed check when the file can't be read.
+fn social_file_checks(config: &Config) -> Result<(Vec<Check>, parser::Profile), Check> {
+    let path = &config.social_file;
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        Check::fail(
+            "Social file",
+            format!("{}: {e}", path.display()),
+            "Set `social_file` in the config, pass `--file`, or run any other command to create it",
+        )
+    })?;
+    let (profile, posts) = parser::parse_file(&content, Some(path.to_string_lossy().to_string()));
+
+    let mut checks = vec![Check::pass("Social file", path.display().to_string())];
+    checks.push(if content.lines().any(|line| line.starts_with("* Posts")) {
+        Check::pass("Posts", format!("{} posts", posts.len()))
+    } else {
+        Check::fail("Posts", "No `* Posts` heading, none of the posts are found", "Add a `* Posts` heading above your posts")
+    });
+    let lint_warnings = lint::lint(&content).len();
+    if lint_warnings > 0 {
+        checks.push(Check::warn(
+            "Lint",
+            format!("{lint_warnings} problems with IDs or polls"),
+            "Run `org-social-rs lint` to see the lines to fix",
+        ));
+    }
+
+    let missing: Vec<String> = [("#+TITLE:", profile.title()), ("#+NICK:", profile.nick())]
+        .into_iter()
+        .filter(|(_, value)| value.trim().is_empty())
+        .map(|(keyword, _)| format!("`{keyword}`"))
+        .collect();
+    checks.push(if missing.is_empty() {
+        Check::pass("Profile", format!("{} ({})", profile.nick(), profile.title()))
+    } else {
+        Check::fail(
+            "Profile",
+            format!("Missing {}", missing.join(" and ")),
+            format!("Add {} lines to the top of the social file", missing.join(" and ")),
+        )
+    });
+
+    if profile.follow().as_ref().is_none_or(Vec::is_empty) {
+        checks.push(Check::warn(
+            "Follows",
+            "Not following anyone, only your own posts are shown",
+            "Add `#+FOLLOW: <nick> <url>` lines, or import them with `follow --import-opml`",
+        ));
+    }
+    Ok((checks, profile))
+}
+
+/// Every followed feed answers, checked concurrently
+async fn follow_checks(follows: Vec<(String, String)>, timeout: Duration) -> Vec<Check> {
+    let handles: Vec<_> = follows
+        .into_iter()
+        .map(|(name, url)| {
+            tokio::spawn(async move {
+                let result = remote::check_reachable(&url, timeout).await;
+                (name, url, result)
+            })
+        })
+        .collect();
+
+    let mut checks = Vec::new();
+    for handle in handles {
+        let Ok((name, url, result)) = handle.await else {
+            continue;
+        };
+        let check_name = format!("Follow {name}");
+        checks.push(match result {
+            Ok(()) => Check::pass(&check_name, url),
+            Err(e) => Check::fail(
+                &check_name,
+                format!("{url}: {e}"),
+                "Check the URL, or remove the `#+FOLLOW:` line if the feed is gone",
+            ),
+        });
+    }
+    checks
+}
@@ -0,0 +1,199 @@
+//! Exporting the feed into standalone HTML and Markdown documents.
+
+use crate::formatting::{self, DisplayTimezone};
+use org_social_lib_rs::{parser, tokenizer::Token};
+
+/// Escape text for use in HTML or XML content and attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape characters Markdown would otherwise treat as formatting
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+const HTML_STYLE: &str = "\
+body { font-family: sans-serif; max-width: 48em; margin: 2em auto; padding: 0 1em; }
+.post { border-bottom: 1px solid #ccc; padding: 1em 0; }
+.author { font-weight: bold; color: #2a7a2a; }
+.meta, time { color: #666; }
+.tag { color: #2a6a9a; margin-left: 0.3em; }
+.content { white-space: pre-wrap; }
+code { background: #f0f0f0; padding: 0 0.2em; }";
+
+fn token_to_html(token: &Token) -> String {
+    match token {
+        Token::PlainText(text) => escape_html(text),
+        Token::Bold(text) => format!("<strong>{}</strong>", escape_html(text)),
+        Token::Italic(text) => format!("<em>{}</em>", escape_html(text)),
+        Token::BoldItalic(text) => format!("<strong><em>{}</em></strong>", escape_html(text)),
+        Token::Strikethrough(text) => format!("<del>{}</del>", escape_html(text)),
+        Token::Underline(text) => format!("<u>{}</u>", escape_html(text)),
+        Token::Link { url, description } => format!(
+            "<a href=\"{}\">{}</a>",
+            escape_html(url),
+            escape_html(description.as_deref().unwrap_or(url))
+        ),
+        Token::Mention { url, username } => format!(
+            "<a class=\"mention\" href=\"{}\">@{}</a>",
+            escape_html(url),
+            escape_html(username)
+        ),
+        Token::InlineCode(text) => format!("<code>{}</code>", escape_html(text)),
+    }
+}
+
+fn token_to_markdown(token: &Token) -> String {
+    match token {
+        // Single newlines would be joined into one paragraph
+        Token::PlainText(text) => escape_markdown(text).replace('\n', "  \n"),
+        Token::Bold(text) => format!("**{}**", escape_markdown(text)),
+        Token::Italic(text) => format!("*{}*", escape_markdown(text)),
+        Token::BoldItalic(text) => format!("***{}***", escape_markdown(text)),
+        Token::Strikethrough(text) => format!("~~{}~~", escape_markdown(text)),
+        Token::Underline(text) => format!("<u>{}</u>", escape_html(text)),
+        Token::Link { url, description: Some(description) } => format!("[{}]({url})", escape_markdown(description)),
+        Token::Link { url, description: None } => format!("<{url}>"),
+        Token::Mention { url, username } => format!("[@{}]({url})", escape_markdown(username)),
+        Token::InlineCode(text) => inline_code_to_markdown(text),
+    }
+}
+
+/// Markdown code span of the text, fenced with one backtick more than its longest run of them
+fn inline_code_to_markdown(text: &str) -> String {
+    let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    // A backtick next to the fence would lengthen it, and Markdown strips
+    // one space from each end of a span that starts and ends with one
+    let pad = text.starts_with('`')
+        || text.ends_with('`')
+        || (text.starts_with(' ') && text.ends_with(' ') && !text.trim().is_empty());
+    if pad {
+        format!("{fence} {text} {fence}")
+    } else {
+        format!("{fence}{text}{fence}")
+    }
+}
+
+/// Tags of the post, language first, like in the CLI output
+fn post_tags(post: &parser::Post) -> Vec<&String> {
+    post.lang().iter().chain(post.tags().iter().flatten()).collect()
+}
+
+fn post_to_html(post: &parser::Post, timezone: DisplayTimezone) -> String {
+    let mut html = format!("<article class=\"post\" id=\"{}\">\n<header>", escape_html(post.id()));
+
+    let author = post.author().as_deref().unwrap_or("unknown");
+    html.push_str(&format!("<span class=\"author\">{}</span>", escape_html(author)));
+    if let Some(time) = post.time() {
+        html.push_str(&format!(
+            " <time datetime=\"{}\">{}</time>",
+            time.to_rfc3339(),
+            formatting::display_time(time, timezone).format("%Y-%m-%d %H:%M")
+        ));
+    }
+    for tag in post_tags(post) {
+        html.push_str(&format!("<span class=\"tag\">#{}</span>", escape_html(tag)));
+    }
+    html.push_str("</header>\n");
+
+    if let Some(mood) = post.mood() {
+        html.push_str(&format!("<p class=\"meta\">Mood: {}</p>\n", escape_html(mood)));
+    }
+    if let Some(reply_to) = post.reply_to() {
+        html.push_str(&format!(
+            "<p class=\"meta\">In reply to <a href=\"{0}\">{0}</a></p>\n",
+            escape_html(reply_to)
+        ));
+    }
+
+    let content: String = post.tokens().iter().map(token_to_html).collect();
+    html.push_str(&format!("<div class=\"content\">{}</div>\n</article>\n", content.trim()));
+    html
+}
+
+fn post_to_markdown(post: &parser::Post, timezone: DisplayTimezone) -> String {
+    let author = post.author().as_deref().unwrap_or("unknown");
+    let mut markdown = format!("## {}", escape_markdown(author));
+    if let Some(time) = post.time() {
+        markdown.push_str(&format!(" · {}", formatting::display_time(time, timezone).format("%Y-%m-%d %H:%M")));
+    }
+    markdown.push_str("\n\n");
+
+    let tags = post_tags(post);
+    if !tags.is_empty() {
+        let tags: Vec<String> = tags.iter().map(|tag| format!("\\#{}", escape_markdown(tag))).collect();
+        markdown.push_str(&format!("{}\n\n", tags.join(" ")));
+    }
+    if let Some(mood) = post.mood() {
+        markdown.push_str(&format!("Mood: {}\n\n", escape_markdown(mood)));
+    }
+    if let Some(reply_to) = post.reply_to() {
+        markdown.push_str(&format!("In reply to <{reply_to}>\n\n"));
+    }
+
+    let content: String = post.tokens().iter().map(token_to_markdown).collect();
+    markdown.push_str(content.trim());
+    markdown.push('\n');
+    markdown
+}
+
+/// Render the posts into a standalone HTML document
+pub fn posts_to_html(posts: &[&parser::Post], title: &str, timezone: DisplayTimezone) -> String {
+    let title = escape_html(title);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+    for post in posts {
+        html.push_str(&post_to_html(post, timezone));
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Render the posts into a Markdown document
+pub fn posts_to_markdown(posts: &[&parser::Post], title: &str, timezone: DisplayTimezone) -> String {
+    let posts: Vec<String> = posts.iter().map(|post| post_to_markdown(post, timezone)).collect();
+    format!("# {}\n\n{}", escape_markdown(title), posts.join("\n---\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_code_fence_is_longer_than_any_backtick_run() {
+        assert_eq!(inline_code_to_markdown("cargo run"), "`cargo run`");
+        assert_eq!(inline_code_to_markdown("a `b` c"), "``a `b` c``");
+        assert_eq!(inline_code_to_markdown("a ``` b"), "````a ``` b````");
+        assert_eq!(inline_code_to_markdown("a `` b ` c"), "```a `` b ` c```");
+    }
+
+    #[test]
+    fn inline_code_is_padded_when_backticks_or_spaces_would_be_lost() {
+        assert_eq!(inline_code_to_markdown("`"), "`` ` ``");
+        assert_eq!(inline_code_to_markdown("``foo"), "``` ``foo ```");
+        assert_eq!(inline_code_to_markdown("foo`"), "`` foo` ``");
+        assert_eq!(inline_code_to_markdown(" x "), "`  x  `");
+        assert_eq!(inline_code_to_markdown("  "), "`  `");
+    }
+}
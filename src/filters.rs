@@ -0,0 +1,60 @@
+//! Post filtering shared by the CLI and the TUI.
+
+use chrono::{Duration, Utc};
+use org_social_lib_rs::parser;
+
+/// Filters narrowing down which posts are shown
+#[derive(Debug, Clone, Default)]
+pub struct PostFilters {
+    /// Only show posts from this source URL
+    pub source: Option<String>,
+    /// Only show posts from the last N days
+    pub days: Option<u32>,
+    /// Only show posts with any of these tags, empty means no filtering
+    pub tags: Vec<String>,
+}
+
+impl PostFilters {
+    pub fn new(source: Option<String>, days: Option<u32>, tags: Vec<String>) -> Self {
+        Self { source, days, tags }
+    }
+
+    /// Check if a post passes all filters
+    pub fn matches(&self, post: &parser::Post) -> bool {
+        // Apply source filter
+        if let Some(source) = &self.source {
+            if post.source().as_ref() != Some(source) {
+                return false;
+            }
+        }
+
+        // Apply days filter
+        if let Some(days) = self.days {
+            let cutoff = Utc::now() - Duration::try_days(days as i64).unwrap_or_default();
+            match post.time() {
+                Some(post_time) if post_time.naive_utc() > cutoff.naive_utc() => {}
+                _ => return false,
+            }
+        }
+
+        // Apply tag filter, the language counts as a tag just like in the CLI output
+        if !self.tags.is_empty() {
+            let has_tag = post.lang().iter()
+                .chain(post.tags().iter().flatten())
+                .any(|post_tag| {
+                    self.tags.iter().any(|tag| tag.trim_start_matches('#').eq_ignore_ascii_case(post_tag))
+                });
+            if !has_tag {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Check if a post is a poll vote or a reaction, a reply carrying only a mood
+pub fn is_reaction(post: &parser::Post) -> bool {
+    post.poll_option().is_some()
+        || (post.reply_to().is_some() && post.mood().is_some() && post.content().trim().is_empty())
+}
@@ -0,0 +1,157 @@
+//! Editing the `#+FOLLOW:` headers of the user's social file.
+
+use std::path::Path;
+
+/// URL without trailing slashes, which are sometimes included by mistake
+pub fn normalize_url(url: &str) -> &str {
+    url.trim().trim_end_matches('/')
+}
+
+/// Check if the URL is among the follows, ignoring trailing slashes
+pub fn is_followed(follows: &[(String, String)], url: &str) -> bool {
+    follows.iter().any(|(_, followed)| normalize_url(followed) == normalize_url(url))
+}
+
+/// Header line following the user, nicks can't contain whitespace so it is replaced
+pub fn follow_line(nick: &str, url: &str) -> String {
+    let nick: Vec<&str> = nick.split_whitespace().collect();
+    if nick.is_empty() {
+        format!("#+FOLLOW: {}", url.trim())
+    } else {
+        format!("#+FOLLOW: {} {}", nick.join("-"), url.trim())
+    }
+}
+
+/// Insert `#+FOLLOW:` lines after the existing follows, or after the last header line if there are none
+pub fn add_follow_lines(content: &str, lines: &[String]) -> String {
+    let mut content_lines: Vec<&str> = content.lines().collect();
+
+    // Only the header before the first heading holds the profile
+    let header_end = content_lines
+        .iter()
+        .position(|line| line.starts_with('*'))
+        .unwrap_or(content_lines.len());
+    let header = &content_lines[..header_end];
+    let insert_at = header
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("#+FOLLOW:"))
+        .or_else(|| header.iter().rposition(|line| line.trim_start().starts_with("#+")))
+        .map_or(0, |index| index + 1);
+
+    content_lines.splice(insert_at..insert_at, lines.iter().map(String::as_str));
+    let line_ending = line_ending(content);
+    let mut new_content = content_lines.join(line_ending);
+    if content.ends_with('\n') || content.is_empty() {
+        new_content.push_str(line_ending);
+    }
+    new_content
+}
+
+/// Line ending used by the content, CRLF files are kept CRLF when lines are added or removed
+fn line_ending(content: &str) -> &'static str {
+    if content.contains("\r\n") { "\r\n" } else { "\n" }
+}
+
+/// Nick and URL of a `#+FOLLOW:` line, the nick is optional
+fn parse_follow_line(line: &str) -> Option<(Option<&str>, &str)> {
+    let value = line.trim_start().strip_prefix("#+FOLLOW:")?;
+    match value.split_whitespace().collect::<Vec<_>>()[..] {
+        [url] => Some((None, url)),
+        [nick, url, ..] => Some((Some(nick), url)),
+        [] => None,
+    }
+}
+
+/// Remove the `#+FOLLOW:` lines of the header following the nick or URL, ignoring trailing slashes.
+/// Returns the new content with the removed lines.
+pub fn remove_follow_lines(content: &str, target: &str) -> (String, Vec<String>) {
+    let mut in_header = true;
+    let mut removed = Vec::new();
+    let kept: Vec<&str> = content
+        .lines()
+        .filter(|line| {
+            in_header &= !line.starts_with('*');
+            let matches = in_header && parse_follow_line(line).is_some_and(|(nick, url)| {
+                nick == Some(target.trim()) || normalize_url(url) == normalize_url(target)
+            });
+            if matches {
+                removed.push(line.trim().to_string());
+            }
+            !matches
+        })
+        .collect();
+
+    let line_ending = line_ending(content);
+    let mut new_content = kept.join(line_ending);
+    if content.ends_with('\n') {
+        new_content.push_str(line_ending);
+    }
+    (new_content, removed)
+}
+
+/// Replace the social file's content, writing a temporary file first so a failure can't corrupt it.
+/// A symlinked social file is written through the link, keeping the file's permissions.
+pub fn write_social_file(path: &Path, content: &str) -> std::io::Result<()> {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    std::fs::write(&temp_path, content)?;
+    if let Ok(metadata) = std::fs::metadata(&path) {
+        std::fs::set_permissions(&temp_path, metadata.permissions())?;
+    }
+    std::fs::rename(&temp_path, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const CRLF_FILE: &str = "#+TITLE: Test\r\n#+NICK: test\r\n#+FOLLOW: bob https://bob.example/social.org\r\n\r\n* Posts\r\n";
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("org-social-rs-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn add_follow_lines_keeps_crlf_line_endings() {
+        let content = add_follow_lines(CRLF_FILE, &[follow_line("alice", "https://alice.example/social.org")]);
+        assert_eq!(
+            content,
+            "#+TITLE: Test\r\n#+NICK: test\r\n#+FOLLOW: bob https://bob.example/social.org\r\n\
+             #+FOLLOW: alice https://alice.example/social.org\r\n\r\n* Posts\r\n"
+        );
+    }
+
+    #[test]
+    fn remove_follow_lines_keeps_crlf_line_endings() {
+        let (content, removed) = remove_follow_lines(CRLF_FILE, "bob");
+        assert_eq!(content, "#+TITLE: Test\r\n#+NICK: test\r\n\r\n* Posts\r\n");
+        assert_eq!(removed, vec!["#+FOLLOW: bob https://bob.example/social.org"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_social_file_writes_through_symlinks_keeping_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let target = temp_path("social-target.org");
+        let link = temp_path("social-link.org");
+        std::fs::write(&target, "old").unwrap();
+        std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o600)).unwrap();
+        let _ = std::fs::remove_file(&link);
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        write_social_file(&link, "new").unwrap();
+        let is_link = std::fs::symlink_metadata(&link).unwrap().file_type().is_symlink();
+        let content = std::fs::read_to_string(&target).unwrap();
+        let mode = std::fs::metadata(&target).unwrap().permissions().mode() & 0o777;
+        std::fs::remove_file(&link).unwrap();
+        std::fs::remove_file(&target).unwrap();
+
+        assert!(is_link);
+        assert_eq!(content, "new");
+        assert_eq!(mode, 0o600);
+    }
+}
@@ -1,5 +1,44 @@
+use crate::search::PostMatcher;
+use chrono::{DateTime, FixedOffset, Local, Utc};
 use colored::*;
 use org_social_lib_rs::{parser, profile::Profile};
+use serde::Serialize;
+
+/// Shown before the author of the user's own posts
+pub const OWN_POST_MARKER: &str = "»";
+
+/// Time zone post times are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DisplayTimezone {
+    /// The user's own clock
+    #[default]
+    Local,
+    /// The offset the post was written with
+    Original,
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Parse `local`, `original`, `utc` or an offset like `+02:00`, None for anything else.
+    /// Names like `Europe/Paris` are not supported, as no time zone database is bundled.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "local" => Some(DisplayTimezone::Local),
+            "original" => Some(DisplayTimezone::Original),
+            "utc" => Some(DisplayTimezone::Fixed(FixedOffset::east_opt(0)?)),
+            offset => offset.parse().ok().map(DisplayTimezone::Fixed),
+        }
+    }
+}
+
+/// Convert a post time into the display time zone
+pub fn display_time(time: DateTime<FixedOffset>, timezone: DisplayTimezone) -> DateTime<FixedOffset> {
+    match timezone {
+        DisplayTimezone::Local => time.with_timezone(&Local).fixed_offset(),
+        DisplayTimezone::Original => time,
+        DisplayTimezone::Fixed(offset) => time.with_timezone(&offset),
+    }
+}
 
 /// Format a profile with colors for CLI display
 pub fn format_profile_colored(profile: &Profile) -> String {
@@ -66,16 +105,107 @@ pub fn format_profile_colored(profile: &Profile) -> String {
     output.join("\n")
 }
 
+/// Format the time elapsed since the given time in a short form, e.g. "3m", "2h", "5d", "3w"
+pub fn relative_time(time: DateTime<Utc>) -> String {
+    let elapsed = Utc::now().signed_duration_since(time);
+
+    let minutes = elapsed.num_minutes();
+    let hours = elapsed.num_hours();
+    let days = elapsed.num_days();
+
+    if minutes < 1 {
+        // Also covers times in the future, e.g. from clock skew
+        "now".to_string()
+    } else if hours < 1 {
+        format!("{minutes}m")
+    } else if days < 1 {
+        format!("{hours}h")
+    } else if days < 7 {
+        format!("{days}d")
+    } else if days < 365 {
+        format!("{}w", days / 7)
+    } else {
+        format!("{}y", days / 365)
+    }
+}
+
+/// Format a post time either relative to now or absolute using the given format
+pub fn format_post_time(time: DateTime<FixedOffset>, relative: bool, absolute_format: &str, timezone: DisplayTimezone) -> String {
+    if relative {
+        relative_time(time.with_timezone(&Utc))
+    } else {
+        display_time(time, timezone).format(absolute_format).to_string()
+    }
+}
+
+/// Highlight all matches of the matcher in the text, styling the rest with `base`
+pub fn highlight_matches(text: &str, matcher: &PostMatcher, base: impl Fn(&str) -> ColoredString) -> String {
+    let mut output = String::new();
+    let mut last_end = 0;
+
+    for range in matcher.find_ranges(text) {
+        if range.start > last_end {
+            output.push_str(&base(&text[last_end..range.start]).to_string());
+        }
+        output.push_str(&text[range.clone()].black().on_yellow().bold().to_string());
+        last_end = range.end;
+    }
+
+    if last_end < text.len() {
+        output.push_str(&base(&text[last_end..]).to_string());
+    }
+
+    output
+}
+
+/// Shorten text to at most `max_chars` chars, ending with "…" when it was cut.
+/// Counts chars rather than bytes, so multibyte text is never split inside a char.
+pub fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    format!("{truncated}…")
+}
+
+/// Split a reply target (`url#id`) into the feed URL and the post ID.
+/// Targets without a `#` are used as both, matching how they were always displayed.
+pub fn split_reply_target(reply_to: &str) -> (&str, &str) {
+    match reply_to.rfind('#') {
+        Some(hash_pos) => (&reply_to[..hash_pos], &reply_to[hash_pos + 1..]),
+        None => (reply_to, reply_to),
+    }
+}
+
+/// Whether the post comes from the user's social file, posts without a source are matched by nick
+pub fn is_own_post(post: &parser::Post, user_nick: &str, user_source: Option<&String>) -> bool {
+    match (post.source(), user_source) {
+        (Some(source), Some(user_source)) => source == user_source,
+        _ => post.author().as_deref() == Some(user_nick),
+    }
+}
+
 /// Format a post with colors for CLI display
-pub fn format_post_colored(post: &parser::Post, profile: Option<&Profile>) -> String {
+pub fn format_post_colored(post: &parser::Post, profile: Option<&Profile>, timezone: DisplayTimezone) -> String {
+    format_post_colored_with_matches(post, profile, None, timezone)
+}
+
+/// Format a post with colors for CLI display, highlighting matches of the matcher if given
+pub fn format_post_colored_with_matches(post: &parser::Post, profile: Option<&Profile>, matcher: Option<&PostMatcher>, timezone: DisplayTimezone) -> String {
     let mut output = String::new();
 
     // Build header line with username, tags, and time
     let mut header = if let Some(author) = post.author() {
-        author.green().bold().to_string()
+        match matcher {
+            Some(matcher) => highlight_matches(author, matcher, |s| s.green().bold()),
+            None => author.green().bold().to_string(),
+        }
     } else {
         "unknown".bright_black().to_string()
     };
+    if profile.is_some_and(|profile| is_own_post(post, profile.nick(), profile.source())) {
+        header.insert_str(0, &format!("{} ", OWN_POST_MARKER.cyan().bold()));
+    }
 
     // Add language as first tag if present
     if let Some(lang) = post.lang() {
@@ -85,14 +215,18 @@ pub fn format_post_colored(post: &parser::Post, profile: Option<&Profile>) -> St
     // Add other tags
     if let Some(tags) = post.tags() {
         for tag in tags {
-            header.push_str(&format!(" {}", format!("#{}", tag).blue()));
+            let tag_display = match matcher {
+                Some(matcher) => format!("{}{}", "#".blue(), highlight_matches(tag, matcher, |s| s.blue())),
+                None => format!("#{tag}").blue().to_string(),
+            };
+            header.push_str(&format!(" {tag_display}"));
         }
     }
 
     // Add timestamp if available
     if let Some(time) = post.time() {
         header.push_str(&format!(" {} {}", "•".bright_black(), 
-            time.format("%Y-%m-%d %H:%M").to_string().bright_black()));
+            display_time(time, timezone).format("%Y-%m-%d %H:%M").to_string().bright_black()));
     }
 
     output.push_str(&format!("{} {}\n", 
@@ -107,52 +241,23 @@ pub fn format_post_colored(post: &parser::Post, profile: Option<&Profile>) -> St
     }
 
     if let Some(reply_to) = post.reply_to() {
-        // Extract the post ID from the reply_to URL 
-        let reply_id = if let Some(hash_pos) = reply_to.rfind('#') {
-            &reply_to[hash_pos + 1..]
-        } else {
-            reply_to
-        };
-        
+        let (base_url, reply_id) = split_reply_target(reply_to);
+
         // Try to map the URL to a nickname from the profile's follow list
-        let reply_display = if let Some(profile) = profile {
-            if let Some(follows) = profile.follow() {
-                // Extract the base URL (without the fragment)
-                let base_url = if let Some(hash_pos) = reply_to.rfind('#') {
-                    &reply_to[..hash_pos]
-                } else {
-                    reply_to
-                };
-                
-                // Normalize URLs by removing trailing slashes for comparison - they might be included by mistake
-                let normalized_base = base_url.trim_end_matches('/');
-                
-                // Find the nickname for this URL
-                if let Some((nick, _)) = follows.iter().find(|(_, url)| url.trim_end_matches('/') == normalized_base) {
-                    format!("{nick}#{reply_id}")
-                } else {
-                    // No nickname found, use url#ID format
-                    format!("{base_url}#{reply_id}")
-                }
+        let reply_display = if let Some(follows) = profile.and_then(|profile| profile.follow().as_ref()) {
+            // Normalize URLs by removing trailing slashes for comparison - they might be included by mistake
+            let normalized_base = crate::follows::normalize_url(base_url);
+
+            // Find the nickname for this URL
+            if let Some((nick, _)) = follows.iter().find(|(_, url)| crate::follows::normalize_url(url) == normalized_base) {
+                format!("{nick}#{reply_id}")
             } else {
-                // No follow list, use url#ID format
-                format!("{}#{}", 
-                    if let Some(hash_pos) = reply_to.rfind('#') {
-                        &reply_to[..hash_pos]
-                    } else {
-                        reply_to
-                    }, 
-                    reply_id)
+                // No nickname found, use url#ID format
+                format!("{base_url}#{reply_id}")
             }
         } else {
-            // No profile, use url#ID format
-            format!("{}#{}", 
-                if let Some(hash_pos) = reply_to.rfind('#') {
-                    &reply_to[..hash_pos]
-                } else {
-                    reply_to
-                }, 
-                reply_id)
+            // No follow list, use url#ID format
+            format!("{base_url}#{reply_id}")
         };
         
         metadata.push(format!("{} {}", "Reply to:".bright_black(), reply_display.magenta()));
@@ -196,7 +301,83 @@ pub fn format_post_colored(post: &parser::Post, profile: Option<&Profile>) -> St
     }
 
     // Add post content
-    output.push_str(post.content());
+    match matcher {
+        Some(matcher) => output.push_str(&highlight_matches(post.content(), matcher, |s| s.normal())),
+        None => output.push_str(post.content()),
+    }
 
     output
 }
+
+/// JSON representation of a post
+#[derive(Serialize)]
+struct PostJson<'a> {
+    author: Option<&'a str>,
+    id: &'a str,
+    time: Option<String>,
+    tags: Option<&'a [String]>,
+    mood: Option<&'a str>,
+    lang: Option<&'a str>,
+    reply_to: Option<&'a str>,
+    content: &'a str,
+}
+
+impl<'a> From<&'a parser::Post> for PostJson<'a> {
+    fn from(post: &'a parser::Post) -> Self {
+        Self {
+            author: post.author().as_deref(),
+            id: post.id(),
+            time: post.time().map(|time| time.to_rfc3339()),
+            tags: post.tags().as_deref(),
+            mood: post.mood().as_deref(),
+            lang: post.lang().as_deref(),
+            reply_to: post.reply_to().as_deref(),
+            content: post.content(),
+        }
+    }
+}
+
+/// Serialize posts into a pretty-printed JSON array
+pub fn posts_to_json(posts: &[&parser::Post]) -> String {
+    let posts: Vec<PostJson> = posts.iter().map(|post| PostJson::from(*post)).collect();
+    serde_json::to_string_pretty(&posts).unwrap_or_else(|_| "[]".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_chars_cuts_emoji_at_characters() {
+        let text = "🎉".repeat(40);
+        assert_eq!(truncate_chars(&text, 30), format!("{}…", "🎉".repeat(29)));
+        // A skin tone modifier is a char of its own
+        assert_eq!(truncate_chars("ok 👍🏽 déjà vu 🎉🎉", 9), "ok 👍🏽 dé…");
+    }
+
+    #[test]
+    fn truncate_chars_cuts_accented_text_at_characters() {
+        let question = "¿Qué opináis del café o té? ¡Votad ya!";
+        // Slicing at byte 30 like the poll titles did would panic
+        assert!(!question.is_char_boundary(30));
+        assert_eq!(truncate_chars(question, 30), "¿Qué opináis del café o té? ¡…");
+    }
+
+    #[test]
+    fn truncate_chars_keeps_text_that_fits() {
+        let text = "é".repeat(30);
+        assert_eq!(truncate_chars(&text, 30), text);
+        assert_eq!(truncate_chars("", 30), "");
+    }
+
+    #[test]
+    fn format_post_time_uses_the_given_timezone() {
+        let time = DateTime::parse_from_rfc3339("2025-03-01T23:30:00+01:00").unwrap();
+        let utc = DisplayTimezone::from_name("utc").unwrap();
+        let tokyo = DisplayTimezone::from_name("+09:00").unwrap();
+        assert_eq!(format_post_time(time, false, "%m-%d %H:%M", utc), "03-01 22:30");
+        assert_eq!(format_post_time(time, false, "%m-%d %H:%M", tokyo), "03-02 07:30");
+        assert_eq!(format_post_time(time, false, "%m-%d %H:%M", DisplayTimezone::Original), "03-01 23:30");
+        assert_eq!(DisplayTimezone::from_name("Europe/Paris"), None);
+    }
+}
@@ -0,0 +1,60 @@
+//! Checking the parsed social file for posts that won't show up the way they were meant to.
+//!
+//! The file is parsed as usual, the line of each post is found the same way the parser
+//! splits posts: every line starting with `**` after the `* Posts` heading starts one.
+
+use org_social_lib_rs::{parser, poll, util};
+use std::collections::HashMap;
+
+/// A problem with a post
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintWarning {
+    /// Line of the post's heading, starting at 1
+    pub line: usize,
+    pub message: String,
+}
+
+/// Problems with the posts of a social file, in file order
+pub fn lint(content: &str) -> Vec<LintWarning> {
+    let (_, posts) = parser::parse_file(content, None);
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(posts_index) = lines.iter().position(|line| line.starts_with("* Posts")) else {
+        return Vec::new();
+    };
+    let post_lines = lines
+        .iter()
+        .enumerate()
+        .skip(posts_index + 1)
+        .filter(|(_, line)| line.starts_with("**"))
+        .map(|(i, _)| i + 1);
+
+    let mut warnings = Vec::new();
+    let mut first_lines: HashMap<&str, usize> = HashMap::new();
+    for (post, line) in posts.iter().zip(post_lines) {
+        let mut warn = |message: String| warnings.push(LintWarning { line, message });
+
+        if post.id().is_empty() {
+            warn("Post has no :ID: property, it can't be replied to".to_string());
+        } else if post.time().is_none() {
+            warn(format!("ID \"{}\" isn't a timestamp, the post has no time", post.id()));
+        } else if let Some(first) = first_lines.get(post.id()) {
+            warn(format!("Duplicate ID \"{}\", already used by the post on line {first}", post.id()));
+        } else {
+            first_lines.insert(post.id(), line);
+        }
+
+        match post.poll_end() {
+            Some(poll_end) if util::parse_timestamp(poll_end).is_err() => {
+                warn(format!(":POLL_END: \"{poll_end}\" isn't a timestamp"));
+            }
+            Some(_) if !poll::is_poll_post(post) => {
+                warn("Post has a :POLL_END: but not two `- [ ]` options in a row, it isn't a poll".to_string());
+            }
+            None if poll::parse_poll_from_content(post.content(), None).is_some() => {
+                warn("Post has poll options but no :POLL_END:, it isn't a poll".to_string());
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
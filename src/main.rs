@@ -1,17 +1,34 @@
 use clap::Parser;
-use cli::Cli;
+use cli::{Cli, Failure};
 use org_social_lib_rs::parser;
 use std::fs;
+use std::process::ExitCode;
 
+mod avatar;
 mod cli;
+mod compose;
 mod config;
+mod doctor;
+mod export;
+mod filters;
+mod follows;
 mod formatting;
+mod lint;
+mod mutes;
+mod opml;
+mod pager;
+mod remote;
+mod search;
+#[cfg(test)]
+mod test_support;
 mod tui;
 
 #[tokio::main]
-async fn main() {
-    // Load configuration
-    let config = match config::Config::load() {
+async fn main() -> ExitCode {
+    let args = Cli::parse();
+
+    // Load configuration, from the selected profile's file if any
+    let config = match config::Config::load(args.profile.as_deref()) {
         Ok(config) => config,
         Err(e) => {
             eprintln!("Warning: Failed to load configuration: {}", e);
@@ -25,8 +42,6 @@ async fn main() {
         eprintln!("Warning: Failed to create default config: {}", e);
     }
 
-    let args = Cli::parse();
-    
     // Merge config with CLI arguments (CLI takes precedence)
     let effective_config = config.merge_with_cli(
         &args
@@ -35,18 +50,39 @@ async fn main() {
     // Configure color output based on the --color flag
     args.configure_colors();
 
+    // Post times fall back to local time, see `Config::merge_with_cli`
+    if formatting::DisplayTimezone::from_name(&effective_config.display_timezone).is_none() {
+        eprintln!("Warning: Unknown display_timezone '{}', showing local times", effective_config.display_timezone);
+    }
+
+    // The doctor checks the social file itself, so it has to run before the file is required
+    if matches!(args.command, cli::Commands::Doctor) {
+        return match doctor::run(&effective_config, args.profile.as_deref()).await {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(failure) => failure.into(),
+        };
+    }
+
+    // Offer to create the user's .org file on the first run
+    if let Err(e) = config::ensure_social_file(&effective_config.social_file) {
+        eprintln!("Error reading file {:?}: {}", effective_config.social_file, e);
+        return Failure::SocialFile.into();
+    }
 
     // Read the user's .org file
     let file_content = match fs::read_to_string(&effective_config.social_file) {
         Ok(content) => content,
         Err(e) => {
             eprintln!("Error reading file {:?}: {}", effective_config.social_file, e);
-            std::process::exit(1);
+            return Failure::SocialFile.into();
         }
     };
 
     let file_path = effective_config.social_file.to_string_lossy().to_string();
     let (user_profile, user_posts) = parser::parse_file(&file_content, Some(file_path.clone()));
 
-    args.handle_command(&user_profile, user_posts, &effective_config).await;
+    match args.handle_command(&user_profile, user_posts, &effective_config).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => failure.into(),
+    }
 }
@@ -0,0 +1,57 @@
+//! Authors and keywords whose posts are hidden from the TUI without unfollowing anyone.
+//!
+//! Authors are identified by the URL of their feed, posts without a source by the author's nick.
+//! The list is persisted like the TUI's post sets, one author per line in the config directory.
+//! Keywords come from the `muted_keywords` config option.
+
+use crate::follows;
+use crate::tui::post_sets;
+use org_social_lib_rs::parser;
+use std::collections::HashSet;
+
+/// File of the muted authors
+pub const MUTED_AUTHORS_FILE: &str = "muted_authors.txt";
+
+/// Load the muted authors, a missing file means nobody is muted
+pub fn load() -> HashSet<String> {
+    post_sets::load_post_set(MUTED_AUTHORS_FILE)
+}
+
+/// Save the muted authors
+pub fn save(muted: &HashSet<String>) -> Result<(), Box<dyn std::error::Error>> {
+    post_sets::save_post_set(MUTED_AUTHORS_FILE, muted)
+}
+
+/// What identifies the post's author in the mute list
+pub fn author_key(post: &parser::Post) -> Option<String> {
+    match post.source() {
+        Some(source) => Some(follows::normalize_url(source).to_string()),
+        None => post.author().clone(),
+    }
+}
+
+/// Whether the post's author is muted
+pub fn is_muted(muted: &HashSet<String>, post: &parser::Post) -> bool {
+    !muted.is_empty() && author_key(post).is_some_and(|key| muted.contains(&key))
+}
+
+/// Whether the post's content or one of its tags contains a keyword, keywords must be lowercase
+pub fn has_muted_keyword(keywords: &[String], post: &parser::Post) -> bool {
+    if keywords.is_empty() {
+        return false;
+    }
+    let content = post.content().to_lowercase();
+    let tags: Vec<String> = post.tags().iter().flatten().map(|tag| tag.to_lowercase()).collect();
+    keywords.iter().any(|keyword| {
+        content.contains(keyword) || tags.iter().any(|tag| tag.contains(keyword.trim_start_matches('#')))
+    })
+}
+
+/// Mute list entry of a followed nick or a feed URL, None for nicks that aren't followed
+pub fn resolve_target(follows: &[(String, String)], target: &str) -> Option<String> {
+    let target = target.trim();
+    if let Some((_, url)) = follows.iter().find(|(nick, _)| nick == target) {
+        return Some(follows::normalize_url(url).to_string());
+    }
+    target.contains("://").then(|| follows::normalize_url(target).to_string())
+}
@@ -0,0 +1,149 @@
+//! Reading and writing followed users as OPML, the outline format feed readers exchange subscriptions in.
+
+use crate::export::escape_html;
+use regex::Regex;
+
+/// Render followed users into an OPML document, with each nick as the outline text
+pub fn follows_to_opml(follows: &[(String, String)], title: &str) -> String {
+    let mut opml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n<head>\n<title>{}</title>\n</head>\n<body>\n",
+        escape_html(title)
+    );
+    for (nick, url) in follows {
+        let nick = escape_html(nick);
+        opml.push_str(&format!(
+            "<outline type=\"rss\" text=\"{nick}\" title=\"{nick}\" xmlUrl=\"{}\"/>\n",
+            escape_html(url)
+        ));
+    }
+    opml.push_str("</body>\n</opml>\n");
+    opml
+}
+
+/// Line number of a byte offset, for error messages
+fn line_at(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Replace the predefined XML entities and character references
+fn unescape_xml(text: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let end = rest[start..].find(';').ok_or_else(|| format!("Unterminated entity in '{text}'"))? + start;
+        let entity = &rest[start + 1..end];
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|decimal| decimal.parse()))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("Unknown entity '&{entity};'"))?,
+        };
+        unescaped.push(c);
+        rest = &rest[end + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+/// Parse the feed outlines of an OPML document into (name, url) pairs.
+///
+/// Outlines without an `xmlUrl` (e.g. folders) are skipped, nested outlines are included.
+/// The name is the outline's `text`, or its `title` if there is no text.
+pub fn parse_opml(opml: &str) -> Result<Vec<(String, String)>, String> {
+    let tag_regex = Regex::new(r#"^<(/?)([A-Za-z_][\w:.-]*)((?:\s+[\w:.-]+\s*=\s*(?:"[^"]*"|'[^']*'))*)\s*(/?)>"#)
+        .expect("valid tag regex");
+    let attribute_regex = Regex::new(r#"([\w:.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("valid attribute regex");
+
+    let mut open_tags: Vec<&str> = Vec::new();
+    let mut seen_root = false;
+    let mut follows = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = opml[position..].find('<') {
+        let start = position + offset;
+        let rest = &opml[start..];
+
+        // Declarations, comments and processing instructions carry no outlines
+        let skipped_end = if rest.starts_with("<!--") {
+            Some(rest.find("-->").map(|end| end + 3))
+        } else if rest.starts_with("<?") {
+            Some(rest.find("?>").map(|end| end + 2))
+        } else if rest.starts_with("<!") {
+            Some(rest.find('>').map(|end| end + 1))
+        } else {
+            None
+        };
+        if let Some(end) = skipped_end {
+            let end = end.ok_or_else(|| format!("Unterminated declaration on line {}", line_at(opml, start)))?;
+            position = start + end;
+            continue;
+        }
+
+        let captures = tag_regex
+            .captures(rest)
+            .ok_or_else(|| format!("Malformed tag on line {}", line_at(opml, start)))?;
+        let closing = !captures[1].is_empty();
+        let name = captures.get(2).map_or("", |name| name.as_str());
+        let self_closing = !captures[4].is_empty();
+
+        if closing {
+            match open_tags.pop() {
+                Some(open) if open == name => {}
+                Some(open) => {
+                    return Err(format!("Expected </{open}> but found </{name}> on line {}", line_at(opml, start)));
+                }
+                None => return Err(format!("Unexpected </{name}> on line {}", line_at(opml, start))),
+            }
+        } else {
+            if open_tags.is_empty() {
+                if seen_root || name != "opml" {
+                    return Err(format!("Expected a single <opml> root element, found <{name}> on line {}", line_at(opml, start)));
+                }
+                seen_root = true;
+            }
+
+            if name == "outline" {
+                let mut text = None;
+                let mut title = None;
+                let mut url = None;
+                for attribute in attribute_regex.captures_iter(&captures[3]) {
+                    let value = attribute.get(2).or(attribute.get(3)).map_or("", |value| value.as_str());
+                    let value = unescape_xml(value).map_err(|e| format!("{e} on line {}", line_at(opml, start)))?;
+                    match &attribute[1] {
+                        "text" => text = Some(value),
+                        "title" => title = Some(value),
+                        "xmlUrl" => url = Some(value),
+                        _ => {}
+                    }
+                }
+                if let Some(url) = url.filter(|url| !url.trim().is_empty()) {
+                    let name = text.filter(|text| !text.trim().is_empty()).or(title).unwrap_or_default();
+                    follows.push((name.trim().to_string(), url.trim().to_string()));
+                }
+            }
+
+            if !self_closing {
+                open_tags.push(name);
+            }
+        }
+
+        position = start + captures[0].len();
+    }
+
+    if let Some(open) = open_tags.last() {
+        return Err(format!("Missing </{open}> at the end of the document"));
+    }
+    if !seen_root {
+        return Err("No <opml> element found".to_string());
+    }
+    Ok(follows)
+}
@@ -0,0 +1,56 @@
+//! Showing long CLI output through the user's `$PAGER`.
+
+use colored::*;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// `less` options used when the user hasn't set `LESS`: keep colors, quit when the output fits
+/// on one screen and leave it on the screen afterwards, like git does
+const DEFAULT_LESS: &str = "FRX";
+
+/// Print the text through `$PAGER` when asked to and stdout is a terminal, directly otherwise
+pub fn page_or_print(text: &str, use_pager: bool) {
+    let pager = std::env::var("PAGER").ok().filter(|pager| !pager.trim().is_empty());
+    if let Some(pager) = pager.filter(|_| use_pager && io::stdout().is_terminal()) {
+        match page(&pager, text) {
+            Ok(()) => return,
+            Err(e) => eprintln!("{} {}", "Warning:".yellow().bold(), format!("Failed to run pager {pager:?}: {e}").red()),
+        }
+    }
+    if !text.is_empty() {
+        println!("{text}");
+    }
+}
+
+/// Write the text to the pager and wait for the user to quit it
+fn page(pager: &str, text: &str) -> io::Result<()> {
+    // Through the shell, so `PAGER="less -S"` works
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", pager]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", pager]);
+        command
+    };
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", DEFAULT_LESS);
+    }
+
+    let mut child = command.stdin(Stdio::piped()).spawn()?;
+    let written = match child.stdin.take() {
+        // Dropping stdin closes the pipe, letting the pager know the text is complete
+        Some(mut stdin) => writeln!(stdin, "{text}"),
+        None => Ok(()),
+    };
+    // The shell couldn't find the pager, the output still has to be shown
+    if child.wait()?.code() == Some(127) {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "command not found"));
+    }
+    // Quitting before reading everything closes the pipe, that's not an error
+    match written {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(e),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,372 @@
+//! Fetching the feeds of followed users, shared by the CLI and the TUI.
+//!
+//! The raw org content of each fetched feed is cached in the `cache` subdirectory of the
+//! config directory. Cached feeds younger than the configured TTL are used without fetching,
+//! older ones are only used when fetching the feed fails.
+
+use crate::config::Config;
+use crate::follows;
+use org_social_lib_rs::{feed, parser};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{mpsc, Semaphore};
+
+/// How remote feeds are fetched
+#[derive(Debug, Clone)]
+pub struct FetchOptions {
+    /// Cached feeds younger than this are used without fetching, zero always fetches
+    pub cache_ttl: Duration,
+    /// Time after which a single feed request is abandoned
+    pub timeout: Duration,
+    /// Maximum number of feeds fetched at the same time
+    pub max_concurrent: usize,
+    /// Notified each time a feed finished loading, successfully or not
+    pub progress: Option<mpsc::UnboundedSender<()>>,
+}
+
+impl FetchOptions {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(config.cache_ttl_seconds),
+            timeout: Duration::from_secs(config.network_timeout_seconds),
+            // No permits would block every fetch forever
+            max_concurrent: config.max_concurrent_fetches.max(1),
+            progress: None,
+        }
+    }
+
+    /// Fetch every feed, using cached copies only when fetching fails
+    pub fn always_fetch(mut self) -> Self {
+        self.cache_ttl = Duration::ZERO;
+        self
+    }
+}
+
+/// Result of loading a single followed feed
+pub struct FetchedFeed {
+    /// Name the feed is followed under
+    pub name: String,
+    pub url: String,
+    pub result: Result<(parser::Profile, Vec<parser::Post>), String>,
+}
+
+/// A followed feed that couldn't be loaded
+pub struct FeedFailure {
+    pub name: String,
+    pub url: String,
+    pub error: String,
+}
+
+/// Posts of the user and followed users, with the profiles of the feeds they came from
+pub struct CombinedFeed {
+    pub feed: feed::Feed,
+    /// Profiles of the user and of every followed feed that could be loaded
+    pub profiles: Vec<parser::Profile>,
+    pub failures: Vec<FeedFailure>,
+}
+
+/// Raw feed content stored on disk
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    /// Unix timestamp of the fetch, in seconds
+    fetched_at: u64,
+    content: String,
+}
+
+impl CacheEntry {
+    fn age(&self) -> Duration {
+        Duration::from_secs(unix_now().saturating_sub(self.fetched_at))
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Path of the cache file for a feed URL
+fn cache_path(url: &str) -> Option<PathBuf> {
+    let dir = Config::get_config_dir().ok()?.join("cache");
+    std::fs::create_dir_all(&dir).ok()?;
+    let name: String = url
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    Some(dir.join(format!("{name}.json")))
+}
+
+/// Read the cached feed, missing or corrupted entries are ignored
+fn read_cache(url: &str) -> Option<CacheEntry> {
+    let contents = std::fs::read_to_string(cache_path(url)?).ok()?;
+    serde_json::from_str::<CacheEntry>(&contents)
+        .ok()
+        // Different URLs can map to the same file name
+        .filter(|entry| entry.url == url)
+}
+
+fn write_cache(url: &str, content: &str) {
+    let Some(path) = cache_path(url) else {
+        return;
+    };
+    let entry = CacheEntry {
+        url: url.to_string(),
+        fetched_at: unix_now(),
+        content: content.to_string(),
+    };
+    if let Ok(json) = serde_json::to_string(&entry) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
+/// Download the raw org content of a feed
+async fn fetch_content(client: &reqwest::Client, url: &str, timeout: Duration) -> Result<String, String> {
+    let request = async {
+        let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP error {}", response.status()));
+        }
+        response.text().await.map_err(|e| e.to_string())
+    };
+
+    match tokio::time::timeout(timeout, request).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("Timeout after {timeout:?}")),
+    }
+}
+
+/// Download a binary file like a profile avatar, without caching it
+pub async fn fetch_bytes(url: &str, timeout: Duration) -> Result<Vec<u8>, String> {
+    let request = async {
+        let response = reqwest::get(url).await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP error {}", response.status()));
+        }
+        response.bytes().await.map(|bytes| bytes.to_vec()).map_err(|e| e.to_string())
+    };
+
+    match tokio::time::timeout(timeout, request).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("Timeout after {timeout:?}")),
+    }
+}
+
+/// Check that a feed answers, without downloading it
+pub async fn check_reachable(url: &str, timeout: Duration) -> Result<(), String> {
+    let request = async {
+        let response = reqwest::Client::new().head(url).send().await.map_err(|e| e.to_string())?;
+        // Some servers only answer GET, they are still there
+        if response.status().is_success() || response.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED {
+            Ok(())
+        } else {
+            Err(format!("HTTP error {}", response.status()))
+        }
+    };
+
+    match tokio::time::timeout(timeout, request).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("Timeout after {timeout:?}")),
+    }
+}
+
+/// Answer of a followed feed to `following --check`
+pub enum FeedCheck {
+    /// The server answered, possibly after redirects
+    Answered {
+        status: reqwest::StatusCode,
+        /// Final URL when the feed was redirected
+        moved_to: Option<String>,
+        /// Number of posts when the answer is a social file, None when it isn't one
+        posts: Option<usize>,
+    },
+    /// No answer, e.g. the host doesn't exist, refused the connection or timed out
+    Unreachable(String),
+}
+
+/// Fetch a feed without the cache, reporting its status, redirects and whether it parses
+pub async fn check_feed(url: &str, timeout: Duration) -> FeedCheck {
+    let request = async {
+        let response = reqwest::get(url).await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        let moved_to = Some(response.url().to_string())
+            .filter(|final_url| crate::follows::normalize_url(final_url) != crate::follows::normalize_url(url));
+        let posts = if status.is_success() {
+            let content = response.text().await.map_err(|e| e.to_string())?;
+            // Like the doctor, a social file without a posts section isn't one
+            content.lines().any(|line| line.starts_with("* Posts"))
+                .then(|| parser::parse_file(&content, Some(url.to_string())).1.len())
+        } else {
+            None
+        };
+        Ok(FeedCheck::Answered { status, moved_to, posts })
+    };
+
+    match tokio::time::timeout(timeout, request).await {
+        Ok(result) => result.unwrap_or_else(FeedCheck::Unreachable),
+        Err(_) => FeedCheck::Unreachable(format!("Timeout after {timeout:?}")),
+    }
+}
+
+/// Load a single feed from the cache or the network, falling back to a stale cache entry on failure
+async fn load_feed(
+    client: &reqwest::Client,
+    url: &str,
+    options: &FetchOptions,
+    semaphore: &Semaphore,
+) -> Result<String, String> {
+    let cached = read_cache(url);
+    if let Some(entry) = &cached {
+        if entry.age() < options.cache_ttl {
+            return Ok(entry.content.clone());
+        }
+    }
+
+    // Only the network requests are limited, cached feeds load right away
+    let fetched = match semaphore.acquire().await {
+        Ok(_permit) => fetch_content(client, url, options.timeout).await,
+        Err(e) => Err(e.to_string()),
+    };
+    match fetched {
+        Ok(content) => {
+            write_cache(url, &content);
+            Ok(content)
+        }
+        Err(e) => cached.map(|entry| entry.content).ok_or(e),
+    }
+}
+
+/// Load the followed feeds concurrently, in the order they were given
+pub async fn fetch_feeds(followed_users: Vec<(String, String)>, options: &FetchOptions) -> Vec<FetchedFeed> {
+    let client = reqwest::Client::new();
+    let semaphore = Arc::new(Semaphore::new(options.max_concurrent));
+
+    let handles: Vec<_> = followed_users
+        .into_iter()
+        .map(|(name, url)| {
+            let client = client.clone();
+            let options = options.clone();
+            let semaphore = semaphore.clone();
+            tokio::spawn(async move {
+                let result = load_feed(&client, &url, &options, &semaphore)
+                    .await
+                    .map(|content| parser::parse_file(&content, Some(url.clone())));
+                if let Some(progress) = &options.progress {
+                    let _ = progress.send(());
+                }
+                FetchedFeed { name, url, result }
+            })
+        })
+        .collect();
+
+    let mut feeds = Vec::new();
+    for handle in handles {
+        if let Ok(fetched) = handle.await {
+            feeds.push(fetched);
+        }
+    }
+    feeds
+}
+
+/// Drop posts already seen earlier in the list, e.g. from a feed followed under two names.
+/// Sources are compared normalized, so a feed followed with and without a trailing slash is one feed.
+fn dedup_posts(posts: &mut Vec<parser::Post>) {
+    let mut seen = HashSet::new();
+    posts.retain(|post| {
+        let key = match post.source() {
+            Some(source) => format!("{}#{}", follows::normalize_url(source), post.id()),
+            None => post.full_id(),
+        };
+        seen.insert(key)
+    });
+}
+
+/// Create the feed of the user's and followed users' posts, newest first.
+/// Feeds that can't be loaded are left out and returned as failures.
+pub async fn create_combined_feed(
+    user_profile: &parser::Profile,
+    user_posts: Vec<parser::Post>,
+    options: &FetchOptions,
+) -> CombinedFeed {
+    let mut posts = feed::Feed::create_user_feed(user_profile, user_posts).posts;
+    let mut profiles = vec![user_profile.clone()];
+    let mut failures = Vec::new();
+
+    let followed_users = user_profile.follow().clone().unwrap_or_default();
+    for fetched in fetch_feeds(followed_users, options).await {
+        match fetched.result {
+            Ok((profile, feed_posts)) => {
+                let author = if profile.nick().is_empty() {
+                    "unknown".to_string()
+                } else {
+                    profile.nick().to_string()
+                };
+                posts.extend(feed_posts.into_iter().map(|mut post| {
+                    post.set_author(author.clone());
+                    post
+                }));
+                profiles.push(profile);
+            }
+            Err(error) => failures.push(FeedFailure {
+                name: fetched.name,
+                url: fetched.url,
+                error,
+            }),
+        }
+    }
+
+    dedup_posts(&mut posts);
+    // Posts without a time go last
+    posts.sort_by_key(|post| std::cmp::Reverse(post.time()));
+    CombinedFeed {
+        feed: feed::Feed { posts },
+        profiles,
+        failures,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{org_post, parse_posts, SOURCE};
+
+    const FIRST: &str = "2025-01-01T10:00:00+0000";
+    const SHARED: &str = "2025-01-01T11:00:00+0000";
+    const LAST: &str = "2025-01-01T12:00:00+0000";
+
+    fn keys(posts: &[parser::Post]) -> Vec<(String, String)> {
+        posts.iter().map(|post| (post.source().clone().unwrap_or_default(), post.id().to_string())).collect()
+    }
+
+    #[test]
+    fn dedup_keeps_the_first_copy_of_posts_from_the_same_feed() {
+        let mut posts = parse_posts(SOURCE, &[org_post(FIRST, None, "First"), org_post(SHARED, None, "Shared")]);
+        posts.extend(parse_posts(SOURCE, &[org_post(SHARED, None, "Shared"), org_post(LAST, None, "Last")]));
+        // The same ID in another feed is another post
+        posts.extend(parse_posts("https://other.example/social.org", &[org_post(SHARED, None, "Other")]));
+
+        dedup_posts(&mut posts);
+        assert_eq!(keys(&posts), vec![
+            (SOURCE.to_string(), FIRST.to_string()),
+            (SOURCE.to_string(), SHARED.to_string()),
+            (SOURCE.to_string(), LAST.to_string()),
+            ("https://other.example/social.org".to_string(), SHARED.to_string()),
+        ]);
+    }
+
+    #[test]
+    fn dedup_ignores_a_trailing_slash_in_the_source() {
+        let mut posts = parse_posts(SOURCE, &[org_post(SHARED, None, "Shared")]);
+        posts.extend(parse_posts(&format!("{SOURCE}/"), &[org_post(SHARED, None, "Shared"), org_post(LAST, None, "Last")]));
+
+        dedup_posts(&mut posts);
+        assert_eq!(keys(&posts), vec![
+            (SOURCE.to_string(), SHARED.to_string()),
+            (format!("{SOURCE}/"), LAST.to_string()),
+        ]);
+    }
+}
@@ -0,0 +1,63 @@
+//! Post search and matching.
+//!
+//! Shared matching logic for finding posts by their content, tags and author.
+
+use org_social_lib_rs::parser;
+use regex::{Regex, RegexBuilder};
+use std::ops::Range;
+
+/// Matcher used to find a query inside posts
+#[derive(Debug, Clone)]
+pub struct PostMatcher {
+    regex: Regex,
+}
+
+impl PostMatcher {
+    /// Create a matcher for a case-insensitive substring query
+    pub fn substring(query: &str) -> Result<Self, regex::Error> {
+        let regex = RegexBuilder::new(&regex::escape(query))
+            .case_insensitive(true)
+            .build()?;
+        Ok(Self { regex })
+    }
+
+    /// Create a matcher from a regular expression
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self { regex: Regex::new(pattern)? })
+    }
+
+    /// Check if the text contains a match
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    /// Byte ranges of all non-empty matches in the text
+    pub fn find_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(text)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.range())
+            .collect()
+    }
+
+    /// Check if the post's content, tags or author match
+    pub fn matches_post(&self, post: &parser::Post) -> bool {
+        if self.is_match(post.content()) {
+            return true;
+        }
+
+        if let Some(author) = post.author() {
+            if self.is_match(author) {
+                return true;
+            }
+        }
+
+        if let Some(tags) = post.tags() {
+            if tags.iter().any(|tag| self.is_match(tag)) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,79 @@
+//! Building posts for unit tests from org text, the way they are read from social files.
+
+use crate::config::Config;
+use crate::filters::PostFilters;
+use crate::remote::FetchOptions;
+use crate::tui::app::TUI;
+use org_social_lib_rs::parser::{self, Post, Profile};
+use std::cell::OnceCell;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Feed the test posts come from, unless a test gives another one
+pub const SOURCE: &str = "https://example.com/social.org";
+
+/// Org text of a post with the given ID, which doubles as its time when it is a timestamp
+pub fn org_post(id: &str, reply_to: Option<&str>, content: &str) -> String {
+    match reply_to {
+        Some(reply_to) => org_post_with_properties(id, &[("REPLY_TO", reply_to)], content),
+        None => org_post_with_properties(id, &[], content),
+    }
+}
+
+/// Org text of a post with the given ID and further properties
+pub fn org_post_with_properties(id: &str, properties: &[(&str, &str)], content: &str) -> String {
+    let properties: String = properties.iter().map(|(name, value)| format!(":{name}: {value}\n")).collect();
+    format!("**\n:PROPERTIES:\n:ID: {id}\n{properties}:END:\n\n{content}\n")
+}
+
+/// Parse the org text of posts as the social file at `source`
+pub fn parse_posts(source: &str, org_posts: &[String]) -> Vec<Post> {
+    parse_social_file(source, org_posts).1
+}
+
+/// Parse the org text of posts as the social file at `source`, with the profile of its header
+pub fn parse_social_file(source: &str, org_posts: &[String]) -> (Profile, Vec<Post>) {
+    let content = format!("#+TITLE: Test\n#+NICK: test\n\n* Posts\n{}", org_posts.concat());
+    parser::parse_file(&content, Some(source.to_string()))
+}
+
+/// Config directory of a test, removed when the test's thread ends
+struct TestConfigDir(PathBuf);
+
+impl Drop for TestConfigDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+thread_local! {
+    static CONFIG_DIR: OnceCell<TestConfigDir> = const { OnceCell::new() };
+}
+
+/// Config directory used by `Config::get_config_dir` in tests, a new temporary one for each test,
+/// so what the TUI saves, e.g. read posts and drafts, stays out of the user's config directory
+pub fn config_dir() -> PathBuf {
+    static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+    CONFIG_DIR.with(|dir| {
+        dir.get_or_init(|| {
+            let name = format!("org-social-rs-tests-{}-{}", std::process::id(), NEXT_DIR.fetch_add(1, Ordering::Relaxed));
+            TestConfigDir(std::env::temp_dir().join(name))
+        })
+        .0
+        .clone()
+    })
+}
+
+/// TUI showing the posts as the user's own, without fetching any feed
+pub async fn tui(org_posts: &[String]) -> TUI {
+    let config = Config::default();
+    let (profile, posts) = parse_social_file(SOURCE, org_posts);
+    TUI::new(&config, &profile, posts, true, PostFilters::default(), &FetchOptions::from_config(&config))
+        .await
+        .unwrap()
+}
+
+/// Full ID of the post with the ID in `SOURCE`
+pub fn full_id(id: &str) -> String {
+    format!("{SOURCE}#{id}")
+}
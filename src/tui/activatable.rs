@@ -11,7 +11,27 @@ use std::collections::HashMap;
 use std::process::Command;
 use std::sync::{Arc, Mutex};
 use org_social_lib_rs::{blocks::ActivatableElement};
+use crate::tui::clipboard::ClipboardHandle;
 use crate::tui::modes::ViewMode;
+use crate::tui::theme::Theme;
+
+/// Status of a poll still open for votes
+pub const POLL_ACTIVE: &str = "Active";
+/// Status of a poll past its end
+pub const POLL_CLOSED: &str = "Closed";
+
+/// Length of the post content shown as a poll's title
+pub const POLL_TITLE_CHARS: usize = 30;
+
+/// Status of a post's poll from its end, polls without a readable end stay active
+pub fn poll_status(post: &org_social_lib_rs::parser::Post) -> &'static str {
+    let ended = post
+        .poll_end()
+        .as_deref()
+        .and_then(crate::tui::validation::parse_poll_end)
+        .is_some_and(|end| end < chrono::Utc::now());
+    if ended { POLL_CLOSED } else { POLL_ACTIVE }
+}
 
 /// Represents an activatable element's position in the rendered content
 #[derive(Debug, Clone)]
@@ -39,6 +59,17 @@ pub enum ActivatableType {
 /// Shared state for collecting activatable elements during rendering
 pub type ActivatableCollector = Arc<Mutex<Vec<(ActivatableType, usize, usize, usize, usize)>>>;
 
+/// What activating the focused element asks the app to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum Activation {
+    /// Show the message in the status bar
+    Message(String),
+    /// Show the posts of the mentioned author
+    OpenAuthor { url: String, username: String },
+    /// Start voting in the poll
+    StartPollVote,
+}
+
 /// Manager for tracking and interacting with activatable elements in the TUI
 #[derive(Debug)]
 pub struct ActivatableManager {
@@ -48,12 +79,16 @@ pub struct ActivatableManager {
     focused_element: Option<usize>,
     /// Next ID to assign to an element
     next_id: usize,
-    /// Block collapse state (original line number -> is_collapsed)
-    collapsed_blocks: HashMap<usize, bool>,
+    /// Block collapse state ((post full ID, original line number) -> is_collapsed), kept for every visited post
+    collapsed_blocks: HashMap<(String, usize), bool>,
+    /// Full ID of the post the elements belong to
+    current_post_id: String,
     /// Processed content with collapsed blocks
     processed_content: Option<String>,
     /// Original activatable elements from the content
     content_elements: Vec<ActivatableElement>,
+    /// Clipboard used as a fallback when links can't be opened
+    clipboard: ClipboardHandle,
 }
 
 impl Default for ActivatableManager {
@@ -69,8 +104,10 @@ impl ActivatableManager {
             focused_element: None,
             next_id: 0,
             collapsed_blocks: HashMap::new(),
+            current_post_id: String::new(),
             processed_content: None,
             content_elements: Vec::new(),
+            clipboard: ClipboardHandle::new(),
         }
     }
 
@@ -82,13 +119,7 @@ impl ActivatableManager {
 
     pub fn process_post(&mut self, post: &org_social_lib_rs::parser::Post) {
         // Clear current elements but keep focus info and poll data
-        let current_focused_type = self.focused_element()
-            .map(|pos| match &pos.element_type {
-                ActivatableType::Hyperlink { url, .. } => format!("hyperlink:{url}"),
-                ActivatableType::Mention { url, .. } => format!("mention:{url}"),
-                ActivatableType::Block { block_type, .. } => format!("block:{}:{}", block_type, pos.original_line),
-                ActivatableType::Poll { post_title, .. } => format!("poll:{}:{}", post_title, pos.original_line),
-            });
+        let current_focused_type = self.focused_element().map(focus_key);
 
         // Save existing poll data before clearing
         let mut saved_poll_data: HashMap<usize, (Option<Vec<(String, usize)>>, usize, String)> = HashMap::new();
@@ -101,23 +132,18 @@ impl ActivatableManager {
         }
 
         self.clear();
+        self.current_post_id = post.full_id();
 
-        // Get a short title from the post content (first 30 characters)
-        let post_title = {
-            let content = post.content();
-            if content.chars().count() > 30 {
-                let truncated: String = content.chars().take(30).collect();
-                format!("{}...", truncated)
-            } else {
-                content.to_string()
-            }
-        };
+        let status = poll_status(post);
+
+        // Get a short title from the post content
+        let post_title = crate::formatting::truncate_chars(post.content(), POLL_TITLE_CHARS);
 
         // Process blocks from the post
         for element in post.blocks() {
             match element {
                 org_social_lib_rs::blocks::ActivatableElement::Block(block) => {
-                    let is_collapsed = element.is_collapsed();
+                    let is_collapsed = element.is_collapsed() || self.is_block_collapsed(element.start_line());
                     self.add_block_element(
                         element.start_line(),
                         element.end_line(),
@@ -133,7 +159,7 @@ impl ActivatableManager {
                     let (vote_counts, total_votes, status) = saved_poll_data
                         .get(&start_line)
                         .cloned()
-                        .unwrap_or((None, 0, "Unknown".to_string()));
+                        .unwrap_or((None, 0, status.to_string()));
                     
                     self.add_poll_element(
                         start_line,
@@ -148,14 +174,20 @@ impl ActivatableManager {
             }
         }
 
+        self.content_elements = post.blocks().to_vec();
+
         // Try to restore focus
         if let Some(focus_key) = current_focused_type {
             self.restore_focus(&focus_key);
         }
     }
 
+    /// Replace the elements with the ones collected while rendering, which know the rows they are shown on.
+    /// The focused element stays focused.
     pub fn update_from_collector(&mut self, collector: &ActivatableCollector) {
         if let Ok(elements_data) = collector.lock() {
+            let focused = self.focused_element().map(focus_key);
+            self.clear();
             for (element_type, line, start_col, end_col, original_line) in elements_data.iter() {
                 match element_type {
                     ActivatableType::Hyperlink { url, display_text } => {
@@ -172,6 +204,9 @@ impl ActivatableManager {
                     }
                 }
             }
+            if let Some(key) = focused {
+                self.restore_focus(&key);
+            }
         }
     }
 
@@ -257,8 +292,8 @@ impl ActivatableManager {
     /// Update poll vote counts for polls in the currently focused post
     pub fn update_poll_results(&mut self, poll_results: &org_social_lib_rs::poll::Poll) {
         for (_, position) in self.elements.iter_mut() {
-            if let ActivatableType::Poll { vote_counts, total_votes, status, .. } = &mut position.element_type {
-                // Update the poll information
+            if let ActivatableType::Poll { vote_counts, total_votes, .. } = &mut position.element_type {
+                // Update the poll information, the status comes from the post's poll end
                 let option_counts: Vec<(String, usize)> = poll_results.options
                     .iter()
                     .map(|option| (option.text.clone(), option.votes))
@@ -266,32 +301,10 @@ impl ActivatableManager {
                 
                 *vote_counts = Some(option_counts);
                 *total_votes = poll_results.total_votes;
-                *status = format!("{:?}", poll_results.status);
             }
         }
     }
 
-    /// Get poll information for display purposes
-    pub fn get_poll_display_info(&self, original_line: usize) -> Option<String> {
-        for (_, position) in &self.elements {
-            if position.original_line == original_line {
-                if let ActivatableType::Poll { post_title, vote_counts, total_votes, status } = &position.element_type {
-                    let mut display_parts = vec![format!("Poll in: {}", post_title)];
-                    
-                    if let Some(counts) = vote_counts {
-                        for (option, votes) in counts {
-                            display_parts.push(format!("  • {}: {} votes", option, votes));
-                        }
-                    }
-                    
-                    display_parts.push(format!("Total: {} votes | Status: {}", total_votes, status));
-                    return Some(display_parts.join("\n"));
-                }
-            }
-        }
-        None
-    }
-
     /// Get poll data for a specific line (used during rendering)
     pub fn get_poll_data_for_line(&self, original_line: usize) -> Option<(Option<Vec<(String, usize)>>, usize, String)> {
         for (_, position) in &self.elements {
@@ -356,6 +369,27 @@ impl ActivatableManager {
         true
     }
 
+    /// Find the element rendered at the given content line and column
+    pub fn element_at(&self, line: usize, col: usize) -> Option<usize> {
+        let mut ids: Vec<_> = self.elements
+            .iter()
+            .filter(|(_, pos)| pos.line == line && col >= pos.start_col && col < pos.end_col)
+            .map(|(&id, _)| id)
+            .collect();
+        ids.sort();
+        ids.first().copied()
+    }
+
+    /// Focus a specific element
+    pub fn focus_element(&mut self, element_id: usize) -> bool {
+        if self.elements.contains_key(&element_id) {
+            self.focused_element = Some(element_id);
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn is_focused(&self, element_id: usize) -> bool {
         self.focused_element == Some(element_id)
     }
@@ -426,21 +460,22 @@ impl ActivatableManager {
         }
     }
 
-    pub fn activate_focused(&mut self, _view_mode: &ViewMode) -> Option<String> {
+    pub fn activate_focused(&mut self, _view_mode: &ViewMode) -> Option<Activation> {
         if let Some(focused) = self.focused_element.and_then(|id| self.elements.get(&id).cloned()) {
-            match &focused.element_type {
+            match focused.element_type {
                 ActivatableType::Hyperlink { url, .. } => {
-                    Some(self.activate_link(url))
+                    Some(Activation::Message(self.activate_link(&url)))
                 }
-                ActivatableType::Mention { url, .. } => {
-                    Some(self.activate_link(url))
+                ActivatableType::Mention { url, username } => {
+                    // Handled by the app, which shows the author's posts
+                    Some(Activation::OpenAuthor { url, username })
                 }
                 ActivatableType::Block { .. } => {
                     self.toggle_block_at_line(focused.original_line);
-                    Some(format!("Toggled block at line {}", focused.original_line + 1))
+                    Some(Activation::Message(format!("Toggled block at line {}", focused.original_line + 1)))
                 }
                 ActivatableType::Poll { .. } => {
-                    Some("StartPollVote".to_string())
+                    Some(Activation::StartPollVote)
                 }
             }
         } else {
@@ -449,20 +484,45 @@ impl ActivatableManager {
     }
 
     pub fn toggle_block_at_line(&mut self, original_line: usize) {
-        let current_state = self.collapsed_blocks.get(&original_line).copied().unwrap_or(false);
-        self.collapsed_blocks.insert(original_line, !current_state);
+        let collapsed = self.is_block_collapsed(original_line);
+        self.collapsed_blocks.insert((self.current_post_id.clone(), original_line), !collapsed);
+    }
+
+    /// Whether the block of the current post starting at the line was collapsed
+    pub fn is_block_collapsed(&self, original_line: usize) -> bool {
+        self.collapsed_blocks.get(&(self.current_post_id.clone(), original_line)).copied().unwrap_or(false)
+    }
+
+    /// Collapse or expand every block of the current post, returns the number of blocks
+    pub fn set_all_blocks_collapsed(&mut self, collapsed: bool) -> usize {
+        let block_lines: Vec<usize> = self.content_elements
+            .iter()
+            .filter(|element| matches!(element, ActivatableElement::Block(_)))
+            .map(|element| element.start_line())
+            .collect();
+        for &line in &block_lines {
+            self.collapsed_blocks.insert((self.current_post_id.clone(), line), collapsed);
+        }
+        block_lines.len()
+    }
+
+    /// Whether any block of the current post is expanded
+    pub fn any_block_expanded(&self) -> bool {
+        self.content_elements
+            .iter()
+            .any(|element| matches!(element, ActivatableElement::Block(_)) && !self.is_block_collapsed(element.start_line()))
     }
 
     pub fn get_processed_content(&self) -> Option<&str> {
         self.processed_content.as_deref()
     }
 
-    pub fn get_collapsed_blocks(&self) -> &HashMap<usize, bool> {
+    pub fn get_collapsed_blocks(&self) -> &HashMap<(String, usize), bool> {
         &self.collapsed_blocks
     }
 
-    /// Activate a hyperlink by opening it
-    fn activate_link(&self, url: &str) -> String {
+    /// Activate a hyperlink by opening it, copying it to the clipboard if that fails
+    fn activate_link(&mut self, url: &str) -> String {
         // Try to open the URL in the default browser with suppressed output
         let result = if cfg!(target_os = "linux") {
             Command::new("xdg-open")
@@ -489,26 +549,22 @@ impl ActivatableManager {
         match result {
             Ok(_) => format!("Opened link: {url}"),
             Err(_) => {
-                // TODO: Implement fallback behavior, maybe copy to clipboard?
-                format!("Failed to open link: {url}")
+                // No browser available (e.g. headless/SSH sessions), copy the link instead
+                match self.clipboard.copy(url) {
+                    Ok(_) => format!("Copied link to clipboard: {url}"),
+                    Err(_) => format!("Failed to open link: {url}"),
+                }
             }
         }
     }
 
-    /// Restore focus to a previously focused element
-    fn restore_focus(&mut self, focus_key: &str) {
-        for (&id, pos) in &self.elements {
-            let key = match &pos.element_type {
-                ActivatableType::Hyperlink { url, .. } => format!("hyperlink:{url}"),
-                ActivatableType::Mention { url, .. } => format!("mention:{url}"),
-                ActivatableType::Block { block_type, .. } => format!("block:{}:{}", block_type, pos.original_line),
-                ActivatableType::Poll { post_title, .. } => format!("poll:{}:{}", post_title, pos.original_line),
-            };
-            if key == focus_key {
-                self.focused_element = Some(id);
-                break;
-            }
-        }
+    /// Restore focus to a previously focused element, the first one with the key if there are several
+    fn restore_focus(&mut self, key: &str) {
+        self.focused_element = self.elements
+            .iter()
+            .filter(|(_, pos)| focus_key(pos) == key)
+            .map(|(&id, _)| id)
+            .min();
     }
 
     /// Get debug information about current state
@@ -522,8 +578,18 @@ impl ActivatableManager {
     }
 }
 
+/// Key identifying an element across re-collections, used to keep it focused
+fn focus_key(pos: &ActivatablePosition) -> String {
+    match &pos.element_type {
+        ActivatableType::Hyperlink { url, .. } => format!("hyperlink:{url}"),
+        ActivatableType::Mention { url, .. } => format!("mention:{url}"),
+        ActivatableType::Block { block_type, .. } => format!("block:{}:{}", block_type, pos.original_line),
+        ActivatableType::Poll { post_title, .. } => format!("poll:{}:{}", post_title, pos.original_line),
+    }
+}
+
 /// Create a styled span for a hyperlink with proper focus highlighting
-pub fn create_hyperlink_span<'a>(text: String, url: &str, activatable_manager: Option<&ActivatableManager>) -> Span<'a> {
+pub fn create_hyperlink_span<'a>(text: String, url: &str, activatable_manager: Option<&ActivatableManager>, theme: &Theme) -> Span<'a> {
     let is_focused = activatable_manager
         .map(|manager| manager.is_url_focused(url))
         .unwrap_or(false);
@@ -536,7 +602,7 @@ pub fn create_hyperlink_span<'a>(text: String, url: &str, activatable_manager: O
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Blue)
+            .fg(theme.link)
             .add_modifier(Modifier::UNDERLINED)
     };
 
@@ -544,7 +610,7 @@ pub fn create_hyperlink_span<'a>(text: String, url: &str, activatable_manager: O
 }
 
 /// Create a styled span for a mention with proper focus highlighting
-pub fn create_mention_span<'a>(text: String, url: &str, activatable_manager: Option<&ActivatableManager>) -> Span<'a> {
+pub fn create_mention_span<'a>(text: String, url: &str, activatable_manager: Option<&ActivatableManager>, theme: &Theme) -> Span<'a> {
     let is_focused = activatable_manager
         .map(|manager| manager.is_mention_focused(url))
         .unwrap_or(false);
@@ -557,7 +623,7 @@ pub fn create_mention_span<'a>(text: String, url: &str, activatable_manager: Opt
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme.mention)
             .add_modifier(Modifier::UNDERLINED)
     };
 
@@ -640,3 +706,61 @@ pub fn collect_poll(collector: &ActivatableCollector, post_title: String, line:
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{org_post, parse_posts, SOURCE};
+
+    fn block_start(manager: &ActivatableManager) -> usize {
+        manager.content_elements
+            .iter()
+            .find(|element| matches!(element, ActivatableElement::Block(_)))
+            .map(|element| element.start_line())
+            .unwrap()
+    }
+
+    fn shown_collapsed(manager: &ActivatableManager) -> bool {
+        manager.elements.values().any(|position| matches!(position.element_type, ActivatableType::Block { is_collapsed: true, .. }))
+    }
+
+    #[test]
+    fn collapsed_block_stays_collapsed_after_visiting_another_post() {
+        let block = "Code:\n#+BEGIN_SRC rust\nfn main() {}\n#+END_SRC";
+        let posts = parse_posts(SOURCE, &[
+            org_post("2025-01-01T10:00:00+0000", None, block),
+            org_post("2025-01-01T11:00:00+0000", None, block),
+        ]);
+        let mut manager = ActivatableManager::new();
+
+        manager.process_post(&posts[0]);
+        let line = block_start(&manager);
+        assert!(!manager.is_block_collapsed(line));
+        manager.toggle_block_at_line(line);
+        assert!(manager.is_block_collapsed(line));
+
+        // The other post's block on the same line has its own state
+        manager.process_post(&posts[1]);
+        assert!(!manager.is_block_collapsed(line));
+        assert!(!shown_collapsed(&manager));
+
+        manager.process_post(&posts[0]);
+        assert!(manager.is_block_collapsed(line));
+        assert!(shown_collapsed(&manager));
+    }
+
+    #[test]
+    fn activating_a_mention_opens_its_author() {
+        let mut manager = ActivatableManager::new();
+        let id = manager.add_mention("https://alice.example/social.org".to_string(), "Alice Doe".to_string(), 0, 0, 10);
+        assert!(manager.focus_element(id));
+
+        assert_eq!(
+            manager.activate_focused(&ViewMode::List),
+            Some(Activation::OpenAuthor {
+                url: "https://alice.example/social.org".to_string(),
+                username: "Alice Doe".to_string(),
+            })
+        );
+    }
+}
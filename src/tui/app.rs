@@ -1,22 +1,137 @@
 //! Core TUI application state and logic.
 
 use super::{
-    activatable::{ActivatableCollector, ActivatableManager},
+    activatable::{self, ActivatableCollector, ActivatableManager, Activation},
+    clipboard::ClipboardHandle,
+    completion::NickCompletion,
+    desktop_notifications,
+    drafts::{self, Draft},
     events::{self, EventResult},
-    modes::{AppMode, ViewMode},
+    external_editor,
+    keybindings::{Action, KeyBindings},
+    theme::Theme,
+    modes::{AppMode, NotificationFilter, ThreadSort, ViewMode},
     navigation::Navigator,
+    post_sets::{self, BOOKMARKS_FILE, READ_POSTS_FILE},
+    shortcodes,
     ui::poll_vote::PollVoteState,
+    undo::{EditKind, Snapshot, UndoHistory},
+    validation,
+    words,
 };
-use chrono::{Duration as ChronoDuration, Utc};
+use crate::config::Config;
+use crate::filters::{self, PostFilters};
+use crate::remote::{self, FetchOptions};
+use crate::search::PostMatcher;
+use crate::{compose, follows, formatting, mutes};
+use chrono::{DateTime, Utc};
 use org_social_lib_rs::{feed, new_post, notifications, parser, poll, reply, threading};
+use ratatui::layout::{Position, Rect};
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::path::PathBuf;
 use std::time::Instant;
 
+/// Time without changes after which the post or reply being written is saved to its draft,
+/// and newly read posts to the read posts file
+const SAVE_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Feed state saved while showing a single author's posts
+pub struct AuthorView {
+    /// Name of the author being shown
+    pub username: String,
+    /// Posts and threads of the full feed
+    posts: Vec<parser::Post>,
+    thread_view: threading::ThreadView,
+    /// View and selection to go back to
+    view_mode: ViewMode,
+    selected_post: usize,
+    selected_thread: usize,
+    selected_thread_post: usize,
+    hidden_reactions: Vec<parser::Post>,
+    muted_posts: Vec<parser::Post>,
+}
+
+/// Everything built from the user's and followed feeds when loading or refreshing
+struct LoadedFeed {
+    posts: Vec<parser::Post>,
+    notification_feed: notifications::NotificationFeed,
+    thread_view: threading::ThreadView,
+    profiles: Vec<parser::Profile>,
+    /// Names of followed feeds that couldn't be fetched
+    failed_feeds: Vec<String>,
+}
+
 /// Application state for the TUI
 pub struct TUI {
+    /// Effective configuration
+    pub config: Config,
+    /// Whether only the user's own posts are shown
+    pub user_only: bool,
+    /// Filters applied to the posts
+    pub filters: PostFilters,
+    /// Whether the event loop should refresh the feed
+    pub refresh_requested: bool,
+    /// Whether the event loop should ring the terminal bell for new notifications
+    pub bell_requested: bool,
+    /// Whether the event loop should open the post content in `$EDITOR`
+    pub external_editor_requested: bool,
+    /// Whether the event loop should exit
+    pub quit_requested: bool,
+    /// Feed URL and name of an author whose feed the event loop should fetch and show
+    pub author_feed_request: Option<(String, String)>,
+    /// Saved feed while only a single author's posts are shown
+    pub author_view: Option<AuthorView>,
+    /// User configured keybindings for browsing mode
+    pub keybindings: KeyBindings,
+    /// Colors used when drawing
+    pub theme: Theme,
     /// All posts to display
     pub posts: Vec<parser::Post>,
+    /// Profiles of the user and the followed feeds, used for the author profile overlay
+    pub profiles: Vec<parser::Profile>,
+    /// Profile shown in the profile overlay
+    pub profile_view: Option<parser::Profile>,
+    /// Whether reactions and poll votes are left out of the list and threaded views
+    pub hide_reactions: bool,
+    /// Reactions and poll votes left out of `posts`, still used for counting poll votes
+    hidden_reactions: Vec<parser::Post>,
+    /// Authors whose posts are left out of the list view, saved across restarts
+    pub muted_authors: HashSet<String>,
+    /// Lowercase `muted_keywords` of the config
+    muted_keywords: Vec<String>,
+    /// Whether posts of muted authors and with muted keywords are shown anyway
+    pub show_muted: bool,
+    /// Posts of muted authors or with muted keywords left out of `posts`,
+    /// still shown in threads with other posts
+    muted_posts: Vec<parser::Post>,
     /// Notification feed
     pub notification_feed: notifications::NotificationFeed,
+    /// Full IDs of notification posts that were viewed in the notifications view
+    pub seen_notifications: HashSet<String>,
+    /// Kinds of notifications shown in the notifications view
+    pub notification_filter: NotificationFilter,
+    /// Full IDs of bookmarked posts, saved across restarts
+    pub bookmarks: HashSet<String>,
+    /// Posts shown in the bookmarks view, kept in sync with `posts` and `bookmarks`
+    pub bookmarked_posts: Vec<parser::Post>,
+    /// Nick and source of the user's profile, identifying their posts
+    user_nick: String,
+    user_source: Option<String>,
+    /// Posts shown in the own posts view, kept in sync with `posts`
+    pub own_posts: Vec<parser::Post>,
+    /// Full IDs of posts that were already viewed, saved across restarts
+    pub read_posts: HashSet<String>,
+    /// Clipboard for copying posts
+    clipboard: ClipboardHandle,
+    /// Full ID of the post whose raw org source is shown, so it resets on navigation
+    raw_view_post: Option<String>,
+    /// Full IDs of thread roots whose replies are hidden in the threaded view
+    pub collapsed_threads: HashSet<String>,
+    /// Order of the threads in the threaded view
+    thread_sort: ThreadSort,
+    /// Whether the post list is hidden so the content takes the full width
+    pub focus_mode: bool,
     /// Threaded view of posts
     pub thread_view: threading::ThreadView,
     /// Current view mode (list or threaded)
@@ -31,135 +146,381 @@ pub struct TUI {
     pub mode: AppMode,
     /// Reply state (when replying to a post)
     pub reply_state: Option<reply::ReplyState>,
-    /// Reply manager for saving replies
-    pub reply_manager: reply::ReplyManager,
     /// New post state (when creating a new post)
     pub new_post_state: Option<new_post::NewPostState>,
-    /// New post manager for saving new posts
-    pub new_post_manager: new_post::NewPostManager,
+    /// Field of the new post that kept it from being submitted
+    pub invalid_new_post_field: Option<new_post::NewPostField>,
+    /// Undo history of the content of the post or reply being written
+    compose_history: UndoHistory,
+    /// Whether the new post window shows the rendered content next to the editor
+    pub show_compose_preview: bool,
+    /// Followed `(nick, url)` pairs offered when typing `@` in the content
+    follows: Vec<(String, String)>,
+    /// Followed nicks matching the `@nick` before the cursor in the content
+    pub nick_completion: Option<NickCompletion>,
+    /// Start of the `@nick` whose completion was dismissed, so it stays closed while typing on
+    dismissed_completion: Option<usize>,
     /// Poll vote state (when voting on a poll)
     pub poll_vote_state: Option<PollVoteState>,
+    /// View and selection to go back to when leaving the poll vote flow
+    poll_vote_origin: Option<(ViewMode, Navigator)>,
     /// Status message to display
     pub status_message: Option<String>,
+    /// Post ID typed in command mode
+    pub command_input: String,
+    /// Query typed in filter mode, narrowing down the list and threaded views
+    pub filter_input: String,
+    /// Indices into `posts` of the posts matching the filter, `None` when not filtering
+    filter_indices: Option<Vec<usize>>,
+    /// Matcher of the applied filter, for highlighting its matches in the content pane
+    filter_matcher: Option<PostMatcher>,
+    /// Threads built from the posts matching the filter
+    filtered_thread_view: Option<threading::ThreadView>,
     /// Cursor blink state (true = visible, false = hidden)
     pub cursor_visible: bool,
     /// Last time cursor blink state changed
     pub last_cursor_blink: Instant,
+    /// When the post or reply being written last changed, while that isn't saved to its draft yet
+    draft_changed_at: Option<Instant>,
+    /// When the last post was newly read, while the read posts aren't saved yet
+    read_posts_changed_at: Option<Instant>,
+    /// When the feed was last loaded or refreshed
+    pub loaded_at: DateTime<Utc>,
     /// Activatable elements manager for tracking and interacting with links and blocks
     pub activatable_manager: ActivatableManager,
     /// Activatable elements collector for gathering elements during rendering
     pub activatable_collector: ActivatableCollector,
+    /// Inner area of the content pane from the last render, used for mouse handling
+    pub content_area: Cell<Option<Rect>>,
+    /// Maximum scroll offset of the content pane from the last render, in wrapped rows
+    pub content_max_scroll: Cell<usize>,
+    /// Maximum scroll offset of the help overlay from the last render
+    pub help_max_scroll: Cell<u16>,
+    /// Rows of the content lines matching the filter, as last rendered
+    pub content_match_rows: RefCell<Vec<usize>>,
 }
 
 impl TUI {
     pub async fn new(
-        file_path: &std::path::Path,
+        config: &Config,
         user_profile: &parser::Profile,
         user_posts: Vec<parser::Post>,
         user_only: bool,
-        source_filter: Option<String>,
-        days_filter: Option<u32>,
+        filters: PostFilters,
+        fetch_options: &FetchOptions,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let feed = if user_only {
-            feed::Feed::create_user_feed(user_profile, user_posts.clone())
-        } else {
-            match feed::Feed::create_combined_feed(user_profile, user_posts.clone()).await {
-                Ok(feed) => feed,
-                Err(_) => {
-                    // Fallback to user posts only
-                    feed::Feed::create_user_feed(user_profile, user_posts.clone())
-                }
-            }
-        };
+        let LoadedFeed { posts, notification_feed, thread_view, profiles, failed_feeds } =
+            Self::load_feed(user_profile, user_posts, user_only, &filters, fetch_options, config.tui_max_posts).await;
 
-        // Create notification feed from all posts for the user
-        let all_posts_for_notifications = if user_only {
-            // If user_only, we only have user posts, so no notifications
-            Vec::new()
+        // Invalid keybindings and colors are reported in the status bar
+        let (keybindings, mut config_warnings) = KeyBindings::from_config(&config.keybindings);
+        let (theme, theme_warnings) = Theme::from_config(&config.theme);
+        config_warnings.extend(theme_warnings);
+        let view_mode = ViewMode::from_name(&config.default_view_mode).unwrap_or_else(|| {
+            config_warnings.push(format!("Unknown view '{}', starting in the list view", config.default_view_mode));
+            ViewMode::List
+        });
+        let thread_sort = ThreadSort::from_name(&config.thread_sort).unwrap_or_else(|| {
+            config_warnings.push(format!("Unknown thread sort '{}', sorting by activity", config.thread_sort));
+            ThreadSort::Activity
+        });
+        let status_message = if !config_warnings.is_empty() {
+            Some(format!("Config warning: {}", config_warnings.join(" | ")))
+        } else if !failed_feeds.is_empty() {
+            Some(format!("Failed to fetch feeds: {}", failed_feeds.join(", ")))
         } else {
-            feed.posts.clone()
+            None
         };
-        let notification_feed = notifications::NotificationFeed::create_notification_feed(
-            user_profile,
-            &user_posts,
-            all_posts_for_notifications,
-        );
-
-        let mut posts: Vec<parser::Post> = feed.posts.into_iter().collect();
-
-        // Apply source filter
-        if let Some(source) = &source_filter {
-            posts.retain(|post| {
-                    post.source().as_ref().map(|s| s == source).unwrap_or(false)
-                });
-        }
-
-        // Apply days filter
-        if let Some(days) = days_filter {
-            let cutoff = Utc::now() - ChronoDuration::try_days(days as i64).unwrap_or_default();
-            posts.retain(|post| {
-                    if let Some(post_time) = post.time() {
-                        post_time.naive_utc() > cutoff.naive_utc()
-                    } else {
-                        false
-                    }
-                });
-        }
-
-        // Create threaded view from posts
-        let thread_view = threading::ThreadView::from_posts(posts.clone());
 
         let mut app = TUI {
+            config: config.clone(),
+            user_only,
+            filters,
+            refresh_requested: false,
+            bell_requested: false,
+            external_editor_requested: false,
+            quit_requested: false,
+            author_feed_request: None,
+            author_view: None,
+            keybindings,
+            theme,
             posts,
+            profiles,
+            profile_view: None,
+            hide_reactions: config.hide_reactions,
+            hidden_reactions: Vec::new(),
+            muted_authors: mutes::load(),
+            muted_keywords: config.muted_keywords
+                .iter()
+                .map(|keyword| keyword.trim().to_lowercase())
+                .filter(|keyword| !keyword.is_empty())
+                .collect(),
+            show_muted: false,
+            muted_posts: Vec::new(),
             notification_feed,
+            seen_notifications: HashSet::new(),
+            notification_filter: NotificationFilter::All,
+            bookmarks: post_sets::load_post_set(BOOKMARKS_FILE),
+            read_posts: post_sets::load_post_set(READ_POSTS_FILE),
+            clipboard: ClipboardHandle::new(),
+            raw_view_post: None,
+            collapsed_threads: HashSet::new(),
+            thread_sort,
+            focus_mode: false,
+            bookmarked_posts: Vec::new(),
+            user_nick: user_profile.nick().to_string(),
+            user_source: user_profile.source().cloned(),
+            own_posts: Vec::new(),
             thread_view,
-            view_mode: ViewMode::List,
+            view_mode,
             navigator: Navigator::new(),
             show_help: false,
             help_scroll: 0,
             mode: AppMode::Browsing,
             reply_state: None,
-            reply_manager: reply::ReplyManager::new(file_path),
             new_post_state: None,
-            new_post_manager: new_post::NewPostManager::new(file_path),
+            invalid_new_post_field: None,
+            compose_history: UndoHistory::default(),
+            show_compose_preview: true,
+            follows: user_profile.follow().clone().unwrap_or_default(),
+            nick_completion: None,
+            dismissed_completion: None,
             poll_vote_state: None,
-            status_message: None,
+            poll_vote_origin: None,
+            status_message,
+            command_input: String::new(),
+            filter_input: String::new(),
+            filter_indices: None,
+            filter_matcher: None,
+            filtered_thread_view: None,
             cursor_visible: true,
             last_cursor_blink: Instant::now(),
+            draft_changed_at: None,
+            read_posts_changed_at: None,
+            loaded_at: Utc::now(),
             activatable_manager: ActivatableManager::new(),
             activatable_collector: ActivatableManager::create_collector(),
+            content_area: Cell::new(None),
+            content_max_scroll: Cell::new(0),
+            help_max_scroll: Cell::new(0),
+            content_match_rows: RefCell::new(Vec::new()),
         };
 
         // Process the initial post content
+        app.apply_reaction_filter();
         app.process_current_post_content();
+        if app.status_message.is_none() && !app.muted_posts.is_empty() {
+            app.status_message = Some(format!("{} posts hidden by filters - press U to show them", app.muted_posts.len()));
+        }
 
         Ok(app)
     }
 
+    /// Build the filtered posts, notifications and threads from the user's and followed feeds
+    async fn load_feed(
+        user_profile: &parser::Profile,
+        user_posts: Vec<parser::Post>,
+        user_only: bool,
+        filters: &PostFilters,
+        fetch_options: &FetchOptions,
+        max_posts: usize,
+    ) -> LoadedFeed {
+        let mut failed_feeds = Vec::new();
+        let mut profiles = vec![user_profile.clone()];
+        let feed = if user_only {
+            feed::Feed::create_user_feed(user_profile, user_posts.clone())
+        } else {
+            let combined = remote::create_combined_feed(user_profile, user_posts.clone(), fetch_options).await;
+            failed_feeds = combined.failures.into_iter().map(|failure| failure.name).collect();
+            profiles = combined.profiles;
+            combined.feed
+        };
+
+        // Keep only the most recent posts passing the source, days and tag filters, the feed is
+        // sorted newest first. Notifications and threads are built from what is kept.
+        let max_posts = if max_posts > 0 { max_posts } else { usize::MAX };
+        let posts: Vec<parser::Post> = feed.posts
+            .into_iter()
+            .filter(|post| filters.matches(post))
+            .take(max_posts)
+            .collect();
+
+        // Create notification feed from all posts for the user
+        let all_posts_for_notifications = if user_only {
+            // If user_only, we only have user posts, so no notifications
+            Vec::new()
+        } else {
+            posts.clone()
+        };
+        let notification_feed = notifications::NotificationFeed::create_notification_feed(
+            user_profile,
+            &user_posts,
+            all_posts_for_notifications,
+        );
+
+        // Create threaded view from posts
+        let thread_view = threading::ThreadView::from_posts(posts.clone());
+
+        LoadedFeed { posts, notification_feed, thread_view, profiles, failed_feeds }
+    }
+
+    /// Request a feed refresh, performed by the event loop after the next draw
+    pub fn request_refresh(&mut self) {
+        self.refresh_requested = true;
+        self.status_message = Some("Refreshing...".to_string());
+    }
+
+    /// Re-read the social file and fetch remote feeds again, keeping the selected post where possible
+    pub async fn refresh_feed(&mut self) {
+        self.refresh_requested = false;
+        self.save_read_posts();
+        // The refreshed feed replaces a single author's posts
+        self.author_view = None;
+
+        let file_content = match std::fs::read_to_string(&self.config.social_file) {
+            Ok(content) => content,
+            Err(e) => {
+                self.status_message = Some(format!("Error reading {}: {e}", self.config.social_file.display()));
+                return;
+            }
+        };
+        let file_path = self.config.social_file.to_string_lossy().to_string();
+        let (user_profile, user_posts) = parser::parse_file(&file_content, Some(file_path));
+
+        let selected_id = self.current_post().map(|post| post.full_id());
+
+        // A manual refresh always fetches, cached feeds are only used when that fails
+        let fetch_options = FetchOptions::from_config(&self.config).always_fetch();
+        let LoadedFeed { posts, notification_feed, thread_view, profiles, failed_feeds } =
+            Self::load_feed(&user_profile, user_posts, self.user_only, &self.filters, &fetch_options, self.config.tui_max_posts).await;
+        self.posts = posts;
+        self.profiles = profiles;
+        self.user_nick = user_profile.nick().to_string();
+        self.user_source = user_profile.source().cloned();
+        self.follows = user_profile.follow().clone().unwrap_or_default();
+        self.loaded_at = Utc::now();
+        self.hidden_reactions.clear();
+        self.muted_posts.clear();
+        // Authors may have been muted with the CLI in the meantime
+        self.muted_authors = mutes::load();
+        let known_notifications: HashSet<String> = self.notification_feed.notifications
+            .iter()
+            .map(|notification| notification.post.full_id())
+            .collect();
+        self.notification_feed = notification_feed;
+        self.announce_new_notifications(&known_notifications);
+        self.thread_view = thread_view;
+        self.apply_reaction_filter();
+
+        self.navigator = Navigator::new();
+        if let Some(selected_id) = selected_id {
+            self.select_post_by_full_id(&selected_id);
+        }
+        self.process_current_post_content();
+
+        self.status_message = if failed_feeds.is_empty() {
+            Some(format!("Loaded {} posts", self.posts.len()))
+        } else {
+            Some(format!("Loaded {} posts, failed to fetch feeds: {}", self.posts.len(), failed_feeds.join(", ")))
+        };
+    }
+
+    /// Tell the user about notifications that weren't there before the refresh, as configured
+    fn announce_new_notifications(&mut self, known: &HashSet<String>) {
+        let new: Vec<&notifications::Notification> = self.notification_feed.notifications
+            .iter()
+            .filter(|notification| !known.contains(&notification.post.full_id()))
+            .collect();
+        self.bell_requested = self.config.bell_on_notification && !new.is_empty();
+        if self.config.desktop_notifications {
+            if let Some((title, body)) = desktop_notifications::notification_text(&new) {
+                // Without a notification daemon there is nothing to fall back to, the feed shows them anyway
+                let _ = desktop_notifications::send(&title, &body);
+            }
+        }
+    }
+
+    /// Select the post with the given full ID in the current view, returns false if it isn't shown
+    pub fn select_post_by_full_id(&mut self, full_id: &str) -> bool {
+        match self.view_mode {
+            ViewMode::List | ViewMode::Bookmarks | ViewMode::OwnPosts => {
+                let posts = match self.view_mode {
+                    ViewMode::Bookmarks => self.bookmarked_posts.iter().collect(),
+                    ViewMode::OwnPosts => self.own_posts.iter().collect(),
+                    _ => self.visible_posts(),
+                };
+                if let Some(index) = posts.iter().position(|post| post.full_id() == full_id) {
+                    self.navigator.selected_post = index;
+                    self.navigator.reset_scroll();
+                    return true;
+                }
+            }
+            ViewMode::Threaded => {
+                for (thread_idx, thread) in self.visible_threads().roots.iter().enumerate() {
+                    if let Some(post_idx) = thread.flatten().iter().position(|post| post.full_id() == full_id) {
+                        self.navigator.selected_thread = thread_idx;
+                        self.navigator.selected_thread_post = post_idx;
+                        self.navigator.reset_scroll();
+                        self.expand_selected_thread();
+                        return true;
+                    }
+                }
+            }
+            ViewMode::Notifications => {
+                if let Some(index) = self.visible_notifications()
+                    .iter()
+                    .position(|notification| notification.post.full_id() == full_id)
+                {
+                    self.navigator.selected_post = index;
+                    self.navigator.reset_scroll();
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
     pub fn handle_event(&mut self, key_event: crossterm::event::KeyEvent) {
         // Reset cursor to visible when user types
         self.reset_cursor();
+        let draft_before = self.current_draft().map(|(_, draft)| draft);
 
-        let event_result = events::handle_key_event(key_event, &self.mode);
+        // Keys picking a nick take precedence while completions are shown
+        let event_result = self
+            .nick_completion
+            .as_ref()
+            .and_then(|_| events::handle_completion_input(key_event))
+            // `n` and `N` jump between matches while a filter is applied, unless they are rebound
+            .or_else(|| {
+                (self.mode == AppMode::Browsing && self.filter_matcher.is_some())
+                    .then(|| events::handle_match_input(key_event, &self.keybindings))
+                    .flatten()
+            })
+            .unwrap_or_else(|| events::handle_key_event(key_event, &self.mode, &self.keybindings));
         
         match event_result {
             EventResult::Quit => {
                 // This will be handled by the main event loop
+                self.quit_requested = true;
             }
             EventResult::Continue => {}
             EventResult::NextPost => {
-                self.navigator.next_post(&self.view_mode, &self.posts, &self.thread_view, Some(&self.notification_feed));
+                let list_len = self.list_len();
+                let threads = self.filtered_thread_view.as_ref().unwrap_or(&self.thread_view);
+                self.navigator.next_post(&self.view_mode, list_len, threads, &self.collapsed_threads);
                 self.process_current_post_content();
             }
             EventResult::PrevPost => {
-                self.navigator.prev_post(&self.view_mode, &self.posts, &self.thread_view, Some(&self.notification_feed));
+                let list_len = self.list_len();
+                let threads = self.filtered_thread_view.as_ref().unwrap_or(&self.thread_view);
+                self.navigator.prev_post(&self.view_mode, list_len, threads, &self.collapsed_threads);
                 self.process_current_post_content();
             }
             EventResult::ScrollDown => {
                 if self.mode == AppMode::Help {
                     self.scroll_help_down();
                 } else {
-                    self.navigator.scroll_down(&self.posts);
+                    self.navigator.scroll_down(self.content_max_scroll.get());
                 }
             }
             EventResult::ScrollUp => {
@@ -173,14 +534,14 @@ impl TUI {
                 if self.mode == AppMode::Help {
                     self.help_scroll = 0;
                 } else {
-                    self.navigator.go_to_first(&self.posts);
+                    self.navigator.go_to_first(self.list_len());
                 }
             }
             EventResult::GoToLast => {
                 if self.mode == AppMode::Help {
                     self.scroll_help_to_bottom();
                 } else {
-                    self.navigator.go_to_last(&self.posts);
+                    self.navigator.go_to_last(self.list_len());
                 }
             }
             EventResult::ToggleView => {
@@ -192,11 +553,25 @@ impl TUI {
             EventResult::StartNewPost => {
                 self.start_new_post();
             }
+            EventResult::NextMatch => {
+                self.next_match();
+            }
+            EventResult::PrevMatch => {
+                self.prev_match();
+            }
             EventResult::ToggleHelp => {
                 self.toggle_help();
             }
             EventResult::Cancel => {
-                self.cancel();
+                if self.mode == AppMode::Browsing && self.filter_indices.is_some() {
+                    self.clear_filter();
+                } else if self.mode == AppMode::Browsing && self.author_view.is_some() {
+                    self.close_author_view();
+                } else if matches!(self.mode, AppMode::Reply | AppMode::NewPost) && !self.is_draft_empty() {
+                    self.mode = AppMode::ConfirmDiscard;
+                } else {
+                    self.cancel();
+                }
             }
             EventResult::ReplyInput(c) => {
                 self.handle_reply_input(c);
@@ -273,6 +648,50 @@ impl TUI {
             EventResult::CountPollVotes => {
                 self.count_poll_votes();
             }
+            EventResult::ToggleBookmark => {
+                self.toggle_bookmark();
+            }
+            EventResult::MarkAllRead => {
+                self.mark_all_read();
+            }
+            EventResult::CopyContent => {
+                self.copy_current_post(false);
+            }
+            EventResult::CopyPostUrl => {
+                self.copy_current_post(true);
+            }
+            EventResult::ToggleRawView => {
+                self.toggle_raw_view();
+            }
+            EventResult::ToggleCollapseThread => {
+                self.toggle_collapse_thread();
+            }
+            EventResult::ToggleAllBlocks => {
+                self.toggle_all_blocks();
+            }
+            EventResult::ToggleReactions => {
+                self.toggle_reactions();
+            }
+            EventResult::ToggleMuteAuthor => {
+                self.toggle_mute_author();
+            }
+            EventResult::ToggleShowMuted => {
+                self.toggle_show_muted();
+            }
+            EventResult::CycleNotificationFilter => {
+                self.cycle_notification_filter();
+            }
+            EventResult::ToggleFocusMode => {
+                self.focus_mode = !self.focus_mode;
+                self.status_message = Some(if self.focus_mode {
+                    "Focus mode, press f to show the post list again".to_string()
+                } else {
+                    "Showing the post list".to_string()
+                });
+            }
+            EventResult::ShowProfile => {
+                self.show_author_profile();
+            }
             EventResult::StartPollVote => {
                 self.start_poll_vote();
             }
@@ -285,13 +704,151 @@ impl TUI {
             EventResult::SubmitPollVote => {
                 self.submit_poll_vote();
             }
+            EventResult::ClickAt { column, row } => {
+                self.activate_at(column, row);
+            }
+            EventResult::Refresh => {
+                self.request_refresh();
+            }
+            EventResult::OpenExternalEditor => {
+                self.request_external_editor();
+            }
+            EventResult::Undo => {
+                self.undo_edit();
+            }
+            EventResult::Redo => {
+                self.redo_edit();
+            }
+            EventResult::WordLeft => {
+                self.move_cursor_by_word(words::prev_word_start);
+            }
+            EventResult::WordRight => {
+                self.move_cursor_by_word(words::next_word_end);
+            }
+            EventResult::DeleteWordBack => {
+                self.delete_word_back();
+            }
+            EventResult::StartCommand => {
+                self.mode = AppMode::Command;
+                self.command_input.clear();
+            }
+            EventResult::CommandInput(c) => {
+                self.command_input.push(c);
+            }
+            EventResult::CommandBackspace => {
+                self.command_input.pop();
+            }
+            EventResult::SubmitCommand => {
+                self.jump_to_post();
+            }
+            EventResult::StartFilter => {
+                self.start_filter();
+            }
+            EventResult::FilterInput(c) => {
+                self.filter_input.push(c);
+                self.apply_filter();
+            }
+            EventResult::FilterBackspace => {
+                self.filter_input.pop();
+                self.apply_filter();
+            }
+            EventResult::LockFilter => {
+                self.lock_filter();
+            }
+            EventResult::ClearFilter => {
+                self.clear_filter();
+            }
+            EventResult::DiscardDraft => {
+                self.discard_draft();
+            }
+            EventResult::KeepEditing => {
+                self.keep_editing();
+            }
+            EventResult::SaveDraft => {
+                self.cancel();
+                self.status_message = Some("Draft saved".to_string());
+            }
+            EventResult::AcceptCompletion => {
+                self.accept_nick_completion();
+            }
+            EventResult::NextCompletion => {
+                if let Some(completion) = &mut self.nick_completion {
+                    completion.select_next();
+                }
+            }
+            EventResult::PrevCompletion => {
+                if let Some(completion) = &mut self.nick_completion {
+                    completion.select_prev();
+                }
+            }
+            EventResult::DismissCompletion => {
+                self.dismissed_completion = self.nick_completion.take().map(|completion| completion.start);
+            }
+            EventResult::TogglePreview => {
+                self.show_compose_preview = !self.show_compose_preview;
+                let state = if self.show_compose_preview { "shown" } else { "hidden" };
+                self.status_message = Some(format!("Preview {state}"));
+            }
+        }
+
+        // Keep the draft on disk up to date while writing, once typing pauses
+        if matches!(self.mode, AppMode::NewPost | AppMode::Reply) {
+            self.update_nick_completion();
+            if self.current_draft().map(|(_, draft)| draft) != draft_before {
+                self.draft_changed_at = Some(Instant::now());
+            }
+        } else {
+            self.nick_completion = None;
+        }
+    }
+
+    pub fn handle_mouse_event(&mut self, mouse_event: crossterm::event::MouseEvent) {
+        // Mouse input is only handled inside the content pane
+        let Some(content_area) = self.content_area.get() else {
+            return;
+        };
+        if !content_area.contains(Position::new(mouse_event.column, mouse_event.row)) {
+            return;
+        }
+
+        match events::handle_mouse_event(mouse_event, &self.mode) {
+            EventResult::ScrollDown => self.navigator.scroll_down(self.content_max_scroll.get()),
+            EventResult::ScrollUp => self.navigator.scroll_up(),
+            EventResult::ClickAt { column, row } => self.activate_at(column, row),
+            _ => {}
+        }
+    }
+
+    /// Focus and activate the element at the given screen position in the content pane
+    pub fn activate_at(&mut self, column: u16, row: u16) {
+        let Some(content_area) = self.content_area.get() else {
+            return;
+        };
+        if !content_area.contains(Position::new(column, row)) {
+            return;
+        }
+
+        let line = self.navigator.scroll_offset + (row - content_area.y) as usize;
+        let col = (column - content_area.x) as usize;
+
+        self.activatable_manager.update_from_collector(&self.activatable_collector);
+        if let Some(element_id) = self.activatable_manager.element_at(line, col) {
+            self.activatable_manager.focus_element(element_id);
+            self.activate_hyperlink();
         }
     }
 
     /// Toggle between list and threaded view
     pub fn toggle_view_mode(&mut self) {
+        let selected_id = self.current_post().map(|post| post.full_id());
         self.view_mode = self.view_mode.toggle();
-        self.navigator.reset_scroll();
+
+        // Keep the same post selected if the new view shows it, otherwise start at the top
+        self.navigator = Navigator::new();
+        if let Some(selected_id) = selected_id {
+            self.select_post_by_full_id(&selected_id);
+        }
+        self.process_current_post_content();
         
         // Update status message to show current view
         self.status_message = Some(format!("Switched to {}", self.view_mode.display_name().to_lowercase()));
@@ -307,18 +864,248 @@ impl TUI {
         };
         
         self.mode = AppMode::Reply;
-        self.reply_state = Some(reply::ReplyState::new(post_id.clone(), initial_tags));
-        self.status_message = Some(format!("Replying to post {post_id}"));
+        let mut reply_state = reply::ReplyState::new(post_id.clone(), initial_tags);
+        let restored = drafts::reply_draft_path(&post_id)
+            .and_then(|path| drafts::load_draft(&path))
+            .map(|draft| draft.apply_to_reply(&mut reply_state))
+            .is_some();
+        self.reply_state = Some(reply_state);
+        self.compose_history = UndoHistory::default();
+        self.status_message = Some(if restored {
+            format!("Replying to post {post_id} (restored draft)")
+        } else {
+            format!("Replying to post {post_id}")
+        });
+    }
+
+    /// Request editing the content in `$EDITOR`, performed by the event loop
+    pub fn request_external_editor(&mut self) {
+        if external_editor::editor_command().is_some() {
+            self.external_editor_requested = true;
+        } else {
+            self.status_message = Some("$EDITOR is not set, using the built-in editor".to_string());
+        }
+    }
+
+    /// Content of the post or reply being written
+    pub fn editor_content(&self) -> Option<&str> {
+        match (&self.new_post_state, &self.reply_state) {
+            (Some(state), _) => Some(&state.content),
+            (None, Some(state)) => Some(&state.content),
+            (None, None) => None,
+        }
+    }
+
+    /// Replace the content of the post or reply being written with the external editor's result
+    pub fn apply_external_edit(&mut self, result: std::io::Result<String>) {
+        let content = match result {
+            Ok(content) => content,
+            Err(e) => {
+                self.status_message = Some(format!("Error running $EDITOR: {e}"));
+                return;
+            }
+        };
+        self.record_content_edit(EditKind::Replace, true);
+        if let Some(state) = &mut self.new_post_state {
+            state.content_cursor = content.len();
+            state.content = content;
+        } else if let Some(state) = &mut self.reply_state {
+            state.content_cursor = content.len();
+            state.content = content;
+        }
+        self.status_message = Some("Content updated from $EDITOR".to_string());
+        self.save_draft();
+    }
+
+    /// Content and cursor of the post or reply being written
+    fn editor_snapshot(&self) -> Option<Snapshot> {
+        match (&self.new_post_state, &self.reply_state) {
+            (Some(state), _) => Some(Snapshot { content: state.content.clone(), cursor: state.content_cursor }),
+            (None, Some(state)) => Some(Snapshot { content: state.content.clone(), cursor: state.content_cursor }),
+            (None, None) => None,
+        }
+    }
+
+    fn restore_editor_snapshot(&mut self, snapshot: Snapshot) {
+        if let Some(state) = &mut self.new_post_state {
+            state.content = snapshot.content;
+            state.content_cursor = snapshot.cursor;
+        } else if let Some(state) = &mut self.reply_state {
+            state.content = snapshot.content;
+            state.content_cursor = snapshot.cursor;
+        }
     }
 
-    /// Cancel current action and return to browsing
+    /// Whether the content field (rather than tags, mood, ...) is being edited
+    fn editing_content(&self) -> bool {
+        match (&self.new_post_state, &self.reply_state) {
+            (Some(state), _) => state.current_field == new_post::NewPostField::Content,
+            (None, Some(state)) => state.current_field == reply::ReplyField::Content,
+            (None, None) => false,
+        }
+    }
+
+    /// Remember the content before it is edited, so the edit can be undone
+    fn record_content_edit(&mut self, kind: EditKind, boundary: bool) {
+        if !self.editing_content() {
+            return;
+        }
+        if let Some(before) = self.editor_snapshot() {
+            // Backspace at the start of the content changes nothing
+            if kind == EditKind::Delete && before.cursor == 0 {
+                return;
+            }
+            self.compose_history.record(before, kind, boundary);
+        }
+    }
+
+    /// Undo the last change to the content being written
+    pub fn undo_edit(&mut self) {
+        let Some(current) = self.editor_snapshot() else { return };
+        match self.compose_history.undo(current) {
+            Some(previous) => self.restore_editor_snapshot(previous),
+            None => self.status_message = Some("Nothing to undo".to_string()),
+        }
+    }
+
+    /// Redo the last undone change to the content being written
+    pub fn redo_edit(&mut self) {
+        let Some(current) = self.editor_snapshot() else { return };
+        match self.compose_history.redo(current) {
+            Some(next) => self.restore_editor_snapshot(next),
+            None => self.status_message = Some("Nothing to redo".to_string()),
+        }
+    }
+
+    /// Content and cursor of the post or reply being written, while the content field is being edited
+    fn content_and_cursor_mut(&mut self) -> Option<(&mut String, &mut usize)> {
+        if !self.editing_content() {
+            return None;
+        }
+        match (&mut self.new_post_state, &mut self.reply_state) {
+            (Some(state), _) => Some((&mut state.content, &mut state.content_cursor)),
+            (None, Some(state)) => Some((&mut state.content, &mut state.content_cursor)),
+            (None, None) => None,
+        }
+    }
+
+    /// Move the cursor in the content to the position found by `target`
+    fn move_cursor_by_word(&mut self, target: fn(&str, usize) -> usize) {
+        if let Some((content, cursor)) = self.content_and_cursor_mut() {
+            *cursor = target(content, *cursor);
+            self.compose_history.end_step();
+        }
+    }
+
+    /// Delete the word before the cursor in the content
+    pub fn delete_word_back(&mut self) {
+        self.record_content_edit(EditKind::Delete, true);
+        if let Some((content, cursor)) = self.content_and_cursor_mut() {
+            let start = words::prev_word_start(content, *cursor);
+            content.replace_range(start..*cursor, "");
+            *cursor = start;
+        }
+    }
+
+    /// Offer the followed nicks matching the `@nick` typed before the cursor
+    fn update_nick_completion(&mut self) {
+        let completion = match self.editor_snapshot() {
+            Some(snapshot) if self.editing_content() => NickCompletion::find(&self.follows, &snapshot.content, snapshot.cursor),
+            _ => None,
+        };
+        let Some(mut completion) = completion else {
+            self.nick_completion = None;
+            self.dismissed_completion = None;
+            return;
+        };
+        if self.dismissed_completion == Some(completion.start) {
+            return;
+        }
+        // Keep the selection while the same nicks are listed
+        if let Some(previous) = &self.nick_completion {
+            if previous.matches == completion.matches {
+                completion.selected = previous.selected;
+            }
+        }
+        self.nick_completion = Some(completion);
+    }
+
+    /// Replace the `@nick` being typed with a mention of the selected follow
+    pub fn accept_nick_completion(&mut self) {
+        let Some(completion) = self.nick_completion.take() else { return };
+        self.record_content_edit(EditKind::Insert, true);
+        if let Some((content, cursor)) = self.content_and_cursor_mut() {
+            let mention = completion.selected_mention();
+            content.replace_range(completion.start..*cursor, &mention);
+            *cursor = completion.start + mention.len();
+        }
+        self.compose_history.end_step();
+    }
+
+    /// Draft of the post or reply being written, with the path it is saved to
+    fn current_draft(&self) -> Option<(Option<PathBuf>, Draft)> {
+        match (&self.new_post_state, &self.reply_state) {
+            (Some(state), _) => Some((drafts::new_post_draft_path(), Draft::from_new_post(state))),
+            (None, Some(state)) => Some((drafts::reply_draft_path(&state.reply_to_id), Draft::from_reply(state))),
+            (None, None) => None,
+        }
+    }
+
+    /// Check if nothing has been written that would be lost by leaving the editor
+    fn is_draft_empty(&self) -> bool {
+        self.current_draft().is_none_or(|(_, draft)| draft.is_empty())
+    }
+
+    /// Persist the post or reply being written, so it can be restored later
+    pub fn save_draft(&mut self) {
+        self.draft_changed_at = None;
+        if let Some((Some(path), draft)) = self.current_draft() {
+            let _ = drafts::save_draft(&path, &draft);
+        }
+    }
+
+    /// Save the draft and the read posts once they have been left unchanged for a moment,
+    /// rather than on every keystroke
+    pub fn save_if_idle(&mut self) {
+        if self.draft_changed_at.is_some_and(|changed_at| changed_at.elapsed() >= SAVE_DELAY) {
+            self.save_draft();
+        }
+        if self.read_posts_changed_at.is_some_and(|changed_at| changed_at.elapsed() >= SAVE_DELAY) {
+            self.save_read_posts();
+        }
+    }
+
+    /// Leave the post or reply being written and delete its draft
+    pub fn discard_draft(&mut self) {
+        if let Some((Some(path), _)) = self.current_draft() {
+            drafts::remove_draft(&path);
+        }
+        self.reply_state = None;
+        self.new_post_state = None;
+        self.cancel();
+        self.status_message = Some("Draft discarded".to_string());
+    }
+
+    /// Go back to the post or reply being written after being asked to discard it
+    pub fn keep_editing(&mut self) {
+        self.mode = if self.new_post_state.is_some() { AppMode::NewPost } else { AppMode::Reply };
+    }
+
+    /// Cancel current action and return to browsing, keeping any draft on disk
     pub fn cancel(&mut self) {
+        self.save_draft();
         self.mode = AppMode::Browsing;
         self.reply_state = None;
         self.new_post_state = None;
         self.poll_vote_state = None;
         self.show_help = false;
+        self.profile_view = None;
         self.status_message = None;
+        // Leaving a poll vote, or the reply it turned into, goes back to the poll
+        if let Some((view_mode, navigator)) = self.poll_vote_origin.take() {
+            self.view_mode = view_mode;
+            self.navigator = navigator;
+        }
     }
 
     /// Toggle help display
@@ -340,24 +1127,37 @@ impl TUI {
         self.help_scroll = self.help_scroll.saturating_sub(1);
     }
 
+    /// Keep the help and content scroll offsets within what the resized terminal shows, after it was redrawn
+    pub fn clamp_scroll_offsets(&mut self) {
+        match self.mode {
+            AppMode::Help => self.help_scroll = self.help_scroll.min(self.help_max_scroll.get()),
+            // The content pane isn't drawn behind these, its limit wasn't measured
+            AppMode::Reply | AppMode::NewPost | AppMode::PollVote | AppMode::ConfirmDiscard => {}
+            _ => self.navigator.scroll_offset = self.navigator.scroll_offset.min(self.content_max_scroll.get()),
+        }
+    }
+
     pub fn scroll_help_to_bottom(&mut self) {
         // Set to a large value, it will be clamped during rendering
         self.help_scroll = 1000;
     }
 
     pub fn handle_reply_input(&mut self, c: char) {
+        self.record_content_edit(EditKind::Insert, c.is_whitespace());
         if let Some(reply_state) = &mut self.reply_state {
             reply_state.handle_input(c);
         }
     }
 
     pub fn handle_reply_newline(&mut self) {
+        self.record_content_edit(EditKind::Insert, true);
         if let Some(reply_state) = &mut self.reply_state {
             reply_state.handle_newline();
         }
     }
 
     pub fn handle_reply_backspace(&mut self) {
+        self.record_content_edit(EditKind::Delete, false);
         if let Some(reply_state) = &mut self.reply_state {
             reply_state.handle_backspace();
         }
@@ -407,40 +1207,67 @@ impl TUI {
     pub fn submit_reply(&mut self) {
         if let Some(reply_state_mut) = &mut self.reply_state {
             reply_state_mut.finalize_tags_input(); // Remember tags when submitting reply
-            if reply_state_mut.is_ready_to_submit() {
-                match self.reply_manager.save_reply(reply_state_mut) {
-                    Ok(success_message) => {
-                        self.status_message = Some(success_message);
-                    }
-                    Err(e) => {
-                        self.status_message = Some(format!("Error saving reply: {e}"));
+            if reply_state_mut.reply_to_id.trim().is_empty() {
+                self.status_message = Some("Reply target missing, press Esc and start the reply again".to_string());
+                return;
+            }
+            // Poll votes need no content
+            if !reply_state_mut.is_ready_to_submit() && reply_state_mut.poll_option.trim().is_empty() {
+                self.status_message = Some("Reply content required".to_string());
+                return;
+            }
+            reply_state_mut.mood = shortcodes::expand_shortcodes(&reply_state_mut.mood);
+            let status = match compose::save_reply(&self.config.social_file, reply_state_mut) {
+                Ok(success_message) => {
+                    if let Some(path) = drafts::reply_draft_path(&reply_state_mut.reply_to_id) {
+                        drafts::remove_draft(&path);
                     }
+                    self.reply_state = None;
+                    success_message
                 }
-                self.cancel();
-            }
+                Err(e) => format!("Error saving reply: {e}"),
+            };
+            self.cancel();
+            self.status_message = Some(status);
         }
     }
 
     /// Start creating a new post
     pub fn start_new_post(&mut self) {
         self.mode = AppMode::NewPost;
-        self.new_post_state = Some(new_post::NewPostState::new(None));
-        self.status_message = Some("Creating new post".to_string());
+        let mut new_post_state = new_post::NewPostState::new(None);
+        let restored = drafts::new_post_draft_path()
+            .and_then(|path| drafts::load_draft(&path))
+            .map(|draft| draft.apply_to_new_post(&mut new_post_state))
+            .is_some();
+        self.new_post_state = Some(new_post_state);
+        self.invalid_new_post_field = None;
+        self.compose_history = UndoHistory::default();
+        self.status_message = Some(if restored {
+            "Creating new post (restored draft)".to_string()
+        } else {
+            "Creating new post".to_string()
+        });
     }
 
     pub fn handle_new_post_input(&mut self, c: char) {
+        self.record_content_edit(EditKind::Insert, c.is_whitespace());
+        self.clear_invalid_new_post_field();
         if let Some(new_post_state) = &mut self.new_post_state {
             new_post_state.handle_input(c);
         }
     }
 
     pub fn handle_new_post_newline(&mut self) {
+        self.record_content_edit(EditKind::Insert, true);
         if let Some(new_post_state) = &mut self.new_post_state {
             new_post_state.handle_newline();
         }
     }
 
     pub fn handle_new_post_backspace(&mut self) {
+        self.record_content_edit(EditKind::Delete, false);
+        self.clear_invalid_new_post_field();
         if let Some(new_post_state) = &mut self.new_post_state {
             new_post_state.handle_backspace();
         }
@@ -458,38 +1285,75 @@ impl TUI {
         }
     }
 
+    /// Stop highlighting the invalid field once it is edited
+    fn clear_invalid_new_post_field(&mut self) {
+        if let Some(state) = &self.new_post_state {
+            if self.invalid_new_post_field.as_ref() == Some(&state.current_field) {
+                self.invalid_new_post_field = None;
+            }
+        }
+    }
+
     /// Submit new post
     pub fn submit_new_post(&mut self) {
         if let Some(new_post_state) = self.new_post_state.as_mut() {
             new_post_state.finalize_tags_input(); // Remember tags when submitting post
-            if new_post_state.is_ready_to_submit() {
-                match self.new_post_manager.save_new_post(new_post_state) {
-                    Ok(success_message) => {
-                        self.status_message = Some(success_message);
-                    }
-                    Err(e) => {
-                        self.status_message = Some(format!("Error saving new post: {e}"));
-                    }
+            if !new_post_state.is_ready_to_submit() {
+                new_post_state.current_field = new_post::NewPostField::Content;
+                self.status_message = Some("Post is empty".to_string());
+                return;
+            }
+            match validation::normalize_poll_end(&new_post_state.poll_end) {
+                Ok(poll_end) => new_post_state.poll_end = poll_end,
+                Err(e) => {
+                    // Keep the post open, with the poll end selected for fixing
+                    new_post_state.current_field = new_post::NewPostField::PollEnd;
+                    new_post_state.poll_end_cursor = new_post_state.poll_end.len();
+                    self.invalid_new_post_field = Some(new_post::NewPostField::PollEnd);
+                    self.status_message = Some(e);
+                    return;
                 }
-                self.cancel();
             }
+            if !new_post_state.poll_end.is_empty() {
+                if let Err(e) = validation::check_poll_options(&new_post_state.content, &new_post_state.poll_end) {
+                    new_post_state.current_field = new_post::NewPostField::Content;
+                    self.invalid_new_post_field = Some(new_post::NewPostField::Content);
+                    self.status_message = Some(e);
+                    return;
+                }
+            }
+            new_post_state.mood = shortcodes::expand_shortcodes(&new_post_state.mood);
+            let status = match compose::save_new_post(&self.config.social_file, new_post_state) {
+                Ok(success_message) => {
+                    if let Some(path) = drafts::new_post_draft_path() {
+                        drafts::remove_draft(&path);
+                    }
+                    self.new_post_state = None;
+                    success_message
+                }
+                Err(e) => format!("Error saving new post: {e}"),
+            };
+            self.cancel();
+            self.status_message = Some(status);
         }
     }
 
     pub fn current_post(&self) -> Option<&parser::Post> {
         match self.view_mode {
-            ViewMode::List => self.posts.get(self.navigator.selected_post),
+            ViewMode::List => match &self.filter_indices {
+                Some(indices) => indices.get(self.navigator.selected_post).and_then(|&index| self.posts.get(index)),
+                None => self.posts.get(self.navigator.selected_post),
+            },
+            ViewMode::Bookmarks => self.bookmarked_posts.get(self.navigator.selected_post),
+            ViewMode::OwnPosts => self.own_posts.get(self.navigator.selected_post),
             ViewMode::Threaded => {
-                if self.thread_view.is_empty() {
-                    return None;
-                }
-                let current_thread = &self.thread_view.roots[self.navigator.selected_thread];
+                let current_thread = self.visible_threads().roots.get(self.navigator.selected_thread)?;
                 let thread_posts = current_thread.flatten();
                 thread_posts.get(self.navigator.selected_thread_post).copied()
             }
             ViewMode::Notifications => {
                 // Get the post from the notification at the selected index
-                self.notification_feed.notifications
+                self.visible_notifications()
                     .get(self.navigator.selected_post)
                     .map(|notification| &notification.post)
             }
@@ -498,10 +1362,542 @@ impl TUI {
 
     pub fn process_current_post_content(&mut self) {
         if let Some(post) = self.current_post().cloned() {
+            if self.view_mode == ViewMode::Notifications {
+                self.seen_notifications.insert(post.full_id());
+            }
+            // Saved once browsing pauses, not for every post passed
+            if self.read_posts.insert(post.full_id()) {
+                self.read_posts_changed_at = Some(Instant::now());
+            }
             self.activatable_manager.process_post(&post);
         }
     }
 
+    /// Persist the read posts if any were read since they were last saved
+    pub fn save_read_posts(&mut self) {
+        if self.read_posts_changed_at.take().is_none() {
+            return;
+        }
+        if let Err(e) = post_sets::save_post_set(READ_POSTS_FILE, &self.read_posts) {
+            self.status_message = Some(format!("Failed to save read posts: {e}"));
+        }
+    }
+
+    /// Collapse every block of the current post if any is expanded, otherwise expand them all
+    pub fn toggle_all_blocks(&mut self) {
+        let collapse = self.activatable_manager.any_block_expanded();
+        let count = self.activatable_manager.set_all_blocks_collapsed(collapse);
+        if count == 0 {
+            self.status_message = Some("Post has no blocks".to_string());
+            return;
+        }
+        self.process_current_post_content();
+        self.status_message = Some(format!("{} {count} blocks", if collapse { "Collapsed" } else { "Expanded" }));
+    }
+
+    /// Collapse or expand the selected thread in the threaded view
+    pub fn toggle_collapse_thread(&mut self) {
+        if self.view_mode != ViewMode::Threaded {
+            self.status_message = Some("Threads can only be collapsed in the threaded view".to_string());
+            return;
+        }
+        let Some(thread) = self.visible_threads().roots.get(self.navigator.selected_thread) else {
+            return;
+        };
+        let reply_count = thread.flatten().len() - 1;
+        if reply_count == 0 {
+            self.status_message = Some("Thread has no replies to collapse".to_string());
+            return;
+        }
+
+        let root_id = thread.post.full_id();
+        if self.collapsed_threads.remove(&root_id) {
+            self.status_message = Some("Expanded thread".to_string());
+        } else {
+            self.collapsed_threads.insert(root_id);
+            // The selected reply is hidden, so the root is selected instead
+            if self.navigator.selected_thread_post != 0 {
+                self.navigator.selected_thread_post = 0;
+                self.navigator.reset_scroll();
+                self.process_current_post_content();
+            }
+            self.status_message = Some(format!("Collapsed thread, {reply_count} replies hidden"));
+        }
+    }
+
+    /// Expand the selected thread if a hidden reply of it is selected
+    fn expand_selected_thread(&mut self) {
+        if self.navigator.selected_thread_post == 0 {
+            return;
+        }
+        if let Some(thread) = self.visible_threads().roots.get(self.navigator.selected_thread) {
+            let root_id = thread.post.full_id();
+            self.collapsed_threads.remove(&root_id);
+        }
+    }
+
+    /// Find the post a reply is replying to among the loaded posts
+    pub fn reply_parent(&self, post: &parser::Post) -> Option<&parser::Post> {
+        find_reply_parent(&self.posts, post)
+    }
+
+    /// Show the profile of the current post's author, or what the post tells about them if the feed isn't loaded
+    pub fn show_author_profile(&mut self) {
+        let Some(post) = self.current_post() else {
+            return;
+        };
+
+        let author = post.author().as_deref().unwrap_or("unknown");
+        let profile = self.profiles.iter()
+            .find(|profile| post.source().is_some() && profile.source() == post.source().as_ref())
+            .or_else(|| self.profiles.iter().find(|profile| profile.nick() == author))
+            .cloned()
+            .unwrap_or_else(|| {
+                let mut profile = parser::Profile::default();
+                profile.set_nick(author.to_string());
+                profile.set_source(post.source().clone());
+                profile
+            });
+
+        self.profile_view = Some(profile);
+        self.mode = AppMode::Profile;
+    }
+
+    /// Check if the current post is shown as raw org source instead of the styled render
+    pub fn is_raw_view_shown(&self) -> bool {
+        self.raw_view_post.is_some()
+            && self.current_post().map(|post| post.full_id()) == self.raw_view_post
+    }
+
+    /// Switch the current post between the styled render and its raw org source
+    pub fn toggle_raw_view(&mut self) {
+        if self.is_raw_view_shown() {
+            self.raw_view_post = None;
+            self.status_message = Some("Showing rendered content".to_string());
+        } else if let Some(post) = self.current_post() {
+            self.raw_view_post = Some(post.full_id());
+            self.status_message = Some("Showing raw org source".to_string());
+        }
+        self.navigator.reset_scroll();
+    }
+
+    /// Copy the current post's content, or its URL (`source#id`), to the clipboard
+    pub fn copy_current_post(&mut self, url: bool) {
+        let Some(post) = self.current_post() else {
+            return;
+        };
+        let (text, what) = if url {
+            (post.full_id(), "post URL")
+        } else {
+            (post.content().trim().to_string(), "post content")
+        };
+
+        self.status_message = Some(match self.clipboard.copy(&text) {
+            Ok(_) if url => format!("Copied {what} to clipboard: {text}"),
+            Ok(_) => format!("Copied {what} to clipboard"),
+            Err(e) => format!("Failed to copy {what}: {e}"),
+        });
+    }
+
+    /// Mark every post shown in the current view as read
+    pub fn mark_all_read(&mut self) {
+        let visible_ids: Vec<String> = match self.view_mode {
+            ViewMode::List => self.visible_posts().iter().map(|post| post.full_id()).collect(),
+            ViewMode::Bookmarks => self.bookmarked_posts.iter().map(|post| post.full_id()).collect(),
+            ViewMode::OwnPosts => self.own_posts.iter().map(|post| post.full_id()).collect(),
+            ViewMode::Threaded => self.visible_threads().roots
+                .iter()
+                .flat_map(|thread| thread.flatten())
+                .map(|post| post.full_id())
+                .collect(),
+            ViewMode::Notifications => self.visible_notifications()
+                .iter()
+                .map(|notification| notification.post.full_id())
+                .collect(),
+        };
+
+        let count = visible_ids.len();
+        self.read_posts.extend(visible_ids);
+        self.status_message = Some(format!("Marked {count} posts as read"));
+        self.read_posts_changed_at = Some(Instant::now());
+        self.save_read_posts();
+    }
+
+    /// Move reactions and poll votes between `posts` and `hidden_reactions`, and muted posts
+    /// between `posts` and `muted_posts`, rebuilding the threads
+    fn apply_reaction_filter(&mut self) {
+        let mut posts = std::mem::take(&mut self.posts);
+        let restored = !self.muted_posts.is_empty() || (!self.hide_reactions && !self.hidden_reactions.is_empty());
+        posts.append(&mut self.muted_posts);
+        if self.hide_reactions {
+            let (hidden, shown): (Vec<_>, Vec<_>) = posts.into_iter().partition(filters::is_reaction);
+            self.hidden_reactions.extend(hidden);
+            posts = shown;
+        } else {
+            posts.append(&mut self.hidden_reactions);
+        }
+        if restored {
+            posts.sort_by_key(|post| std::cmp::Reverse(post.time()));
+        }
+        // A single author's posts are all shown, even when the author is muted
+        if self.author_view.is_none() {
+            let (muted, shown): (Vec<_>, Vec<_>) = posts.into_iter().partition(|post| self.is_muted(post));
+            self.muted_posts = muted;
+            posts = shown;
+        }
+        self.posts = posts;
+
+        // Muted posts stay in threads with other authors' posts, so replies keep their context
+        let mut thread_view = threading::ThreadView::from_posts(self.posts.iter().chain(&self.muted_posts).cloned().collect());
+        if !self.muted_posts.is_empty() {
+            thread_view.roots.retain(|root| root.flatten().iter().any(|post| !self.is_muted(post)));
+        }
+        self.thread_view = thread_view;
+        self.thread_sort.apply(&mut self.thread_view);
+        self.update_bookmarked_posts();
+        self.update_own_posts();
+        self.update_filtered_posts();
+    }
+
+    /// Rebuild the shown posts after what is hidden changed, keeping the current post selected if it is still shown
+    fn reapply_hidden_posts(&mut self, selected_id: Option<String>) {
+        self.apply_reaction_filter();
+
+        self.navigator = Navigator::new();
+        if let Some(selected_id) = selected_id {
+            self.select_post_by_full_id(&selected_id);
+        }
+        if matches!(self.view_mode, ViewMode::Bookmarks | ViewMode::OwnPosts) {
+            self.clamp_bookmark_selection();
+        }
+        self.process_current_post_content();
+    }
+
+    /// Show or hide reactions and poll votes, keeping the current post selected if it is still shown
+    pub fn toggle_reactions(&mut self) {
+        let selected_id = self.current_post().map(|post| post.full_id());
+        self.hide_reactions = !self.hide_reactions;
+        self.reapply_hidden_posts(selected_id);
+        self.status_message = Some(if self.hide_reactions {
+            format!("Hiding {} reactions and poll votes", self.hidden_reactions.len())
+        } else {
+            "Showing reactions and poll votes".to_string()
+        });
+    }
+
+    /// Mute the current post's author, or unmute them if they already are
+    pub fn toggle_mute_author(&mut self) {
+        let Some(post) = self.current_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return;
+        };
+        if self.is_own_post(post) {
+            self.status_message = Some("You can't mute yourself".to_string());
+            return;
+        }
+        let Some(key) = mutes::author_key(post) else {
+            self.status_message = Some("The post has no author to mute".to_string());
+            return;
+        };
+        let author = post.author().clone().unwrap_or_else(|| key.clone());
+        let selected_id = post.full_id();
+
+        let muted = self.muted_authors.insert(key.clone());
+        if !muted {
+            self.muted_authors.remove(&key);
+        }
+        self.reapply_hidden_posts(Some(selected_id));
+
+        self.status_message = Some(match mutes::save(&self.muted_authors) {
+            Err(e) => format!("Failed to save muted authors: {e}"),
+            Ok(()) if muted => format!("Muted {author}, hiding {} posts", self.muted_posts.len()),
+            Ok(()) => format!("Unmuted {author}"),
+        });
+    }
+
+    /// Whether the post is hidden for its author or a keyword, unless muted posts are shown anyway
+    pub fn is_muted(&self, post: &parser::Post) -> bool {
+        !self.show_muted
+            && (mutes::is_muted(&self.muted_authors, post) || mutes::has_muted_keyword(&self.muted_keywords, post))
+    }
+
+    /// Number of posts left out of the list view for their author or a keyword
+    pub fn muted_count(&self) -> usize {
+        self.muted_posts.len()
+    }
+
+    /// Temporarily show the posts of muted authors and with muted keywords, or hide them again
+    pub fn toggle_show_muted(&mut self) {
+        let selected_id = self.current_post().map(|post| post.full_id());
+        let hidden = self.muted_posts.len();
+        self.show_muted = !self.show_muted;
+        self.reapply_hidden_posts(selected_id);
+        self.status_message = Some(if self.show_muted {
+            format!("Showing {hidden} muted posts - press m on one to unmute its author, U to hide them again")
+        } else {
+            format!("{} posts hidden by filters", self.muted_posts.len())
+        });
+    }
+
+    /// Number of posts in the list, bookmarks or own posts view
+    fn list_len(&self) -> usize {
+        match self.view_mode {
+            ViewMode::Bookmarks => self.bookmarked_posts.len(),
+            ViewMode::OwnPosts => self.own_posts.len(),
+            ViewMode::Notifications => self.visible_notifications().len(),
+            _ => self.filter_indices.as_ref().map_or(self.posts.len(), Vec::len),
+        }
+    }
+
+    /// Posts shown in the list view, narrowed down by the filter
+    pub fn visible_posts(&self) -> Vec<&parser::Post> {
+        match &self.filter_indices {
+            Some(indices) => indices.iter().filter_map(|&index| self.posts.get(index)).collect(),
+            None => self.posts.iter().collect(),
+        }
+    }
+
+    /// Notifications shown in the notifications view, narrowed down by their kind
+    pub fn visible_notifications(&self) -> Vec<&notifications::Notification> {
+        self.notification_feed.notifications
+            .iter()
+            .filter(|notification| self.notification_filter.matches(&notification.notification_type))
+            .collect()
+    }
+
+    /// Show the next kind of notifications: all, mentions or replies
+    pub fn cycle_notification_filter(&mut self) {
+        if self.view_mode != ViewMode::Notifications {
+            self.status_message = Some("Notifications can only be filtered in the notifications view".to_string());
+            return;
+        }
+        let selected_id = self.current_post().map(|post| post.full_id());
+        self.notification_filter = self.notification_filter.next();
+
+        // Keep the same notification selected if it is still shown
+        self.navigator = Navigator::new();
+        if let Some(selected_id) = selected_id {
+            self.select_post_by_full_id(&selected_id);
+        }
+        self.process_current_post_content();
+        let count = self.visible_notifications().len();
+        self.status_message = Some(match self.notification_filter {
+            NotificationFilter::All => format!("Showing all notifications ({count})"),
+            filter => format!("Showing {} only ({count})", filter.display_name().to_lowercase()),
+        });
+    }
+
+    /// Threads shown in the threaded view, narrowed down by the filter
+    pub fn visible_threads(&self) -> &threading::ThreadView {
+        self.filtered_thread_view.as_ref().unwrap_or(&self.thread_view)
+    }
+
+    /// Query the list and threaded views are narrowed down by, if any
+    pub fn active_filter(&self) -> Option<&str> {
+        self.filter_indices.as_ref().map(|_| self.filter_input.trim())
+    }
+
+    /// Matcher of the applied filter, None when not filtering
+    pub fn filter_matcher(&self) -> Option<&PostMatcher> {
+        self.filter_matcher.as_ref()
+    }
+
+    /// Start typing a filter for the list and threaded views
+    pub fn start_filter(&mut self) {
+        if !matches!(self.view_mode, ViewMode::List | ViewMode::Threaded) {
+            self.status_message = Some("Filtering is only available in the list and threaded views".to_string());
+            return;
+        }
+        self.mode = AppMode::Filter;
+    }
+
+    /// Recompute the posts matching the filter after the query or the posts changed
+    fn update_filtered_posts(&mut self) {
+        let query = self.filter_input.trim();
+        let matcher = (!query.is_empty()).then(|| PostMatcher::substring(query).ok()).flatten();
+        let Some(matcher) = matcher else {
+            self.filter_indices = None;
+            self.filtered_thread_view = None;
+            self.filter_matcher = None;
+            return;
+        };
+
+        let indices: Vec<usize> = self.posts
+            .iter()
+            .enumerate()
+            .filter(|(_, post)| matcher.matches_post(post))
+            .map(|(index, _)| index)
+            .collect();
+        let matching_posts: Vec<parser::Post> = indices.iter().map(|&index| self.posts[index].clone()).collect();
+        let matching_ids: HashSet<String> = matching_posts.iter().map(|post| post.full_id()).collect();
+        let mut thread_view = threading::ThreadView::from_posts(matching_posts);
+
+        // Parents that don't match become "not available" placeholders, show their replies as threads instead
+        thread_view.roots = std::mem::take(&mut thread_view.roots)
+            .into_iter()
+            .flat_map(|root| {
+                if matching_ids.contains(&root.post.full_id()) {
+                    vec![root]
+                } else {
+                    root.replies
+                }
+            })
+            .collect();
+        self.thread_sort.apply(&mut thread_view);
+        self.filtered_thread_view = Some(thread_view);
+        self.filter_indices = Some(indices);
+        self.filter_matcher = Some(matcher);
+    }
+
+    /// Apply the changed filter, keeping the current post selected if it still matches
+    fn apply_filter(&mut self) {
+        let selected_id = self.current_post().map(|post| post.full_id());
+        self.update_filtered_posts();
+
+        self.navigator = Navigator::new();
+        if let Some(selected_id) = selected_id {
+            self.select_post_by_full_id(&selected_id);
+        }
+        self.process_current_post_content();
+    }
+
+    /// Stop typing and keep the filter applied while browsing
+    fn lock_filter(&mut self) {
+        self.mode = AppMode::Browsing;
+        let bound_next = self.keybindings.key_name(Action::NextMatch);
+        let next = bound_next.unwrap_or("n");
+        let prev = self.keybindings.key_name(Action::PrevMatch).unwrap_or("N");
+        // The default match keys take `n` from starting a new post until the filter is cleared
+        let new_post_note = if bound_next.is_none() && self.keybindings.key_name(Action::NewPost).is_none() {
+            ", n starts a new post again once cleared"
+        } else {
+            ""
+        };
+        self.status_message = self.active_filter().map(|query| {
+            format!("Showing {} posts matching '{query}' - {next}/{prev}: next/previous match, Esc: clear{new_post_note}", self.list_len())
+        });
+    }
+
+    /// Scroll to the next line matching the filter, going on to the next matching post after the last one
+    pub fn next_match(&mut self) {
+        if self.filter_matcher.is_none() {
+            self.status_message = Some("No filter applied, press / to filter".to_string());
+            return;
+        }
+        let scroll = self.navigator.scroll_offset;
+        let max_scroll = self.content_max_scroll.get();
+        // Matches already at the top of the pane, or that can't be scrolled up any further, were seen
+        let next_row = self.content_match_rows.borrow().iter().copied().find(|&row| row > scroll && scroll < max_scroll);
+        if let Some(row) = next_row {
+            self.navigator.scroll_offset = row.min(max_scroll);
+            return;
+        }
+
+        let selected_id = self.current_post().map(|post| post.full_id());
+        let list_len = self.list_len();
+        let threads = self.filtered_thread_view.as_ref().unwrap_or(&self.thread_view);
+        self.navigator.next_post(&self.view_mode, list_len, threads, &self.collapsed_threads);
+        if self.current_post().map(|post| post.full_id()) == selected_id {
+            self.navigator.go_to_first(list_len);
+            self.status_message = Some("Wrapped around to the first match".to_string());
+        }
+        self.process_current_post_content();
+    }
+
+    /// Scroll to the previous line matching the filter, going back to the previous matching post before the first one
+    pub fn prev_match(&mut self) {
+        if self.filter_matcher.is_none() {
+            self.status_message = Some("No filter applied, press / to filter".to_string());
+            return;
+        }
+        let scroll = self.navigator.scroll_offset.min(self.content_max_scroll.get());
+        let prev_row = self.content_match_rows.borrow().iter().copied().rev().find(|&row| row < scroll);
+        if let Some(row) = prev_row {
+            self.navigator.scroll_offset = row;
+            return;
+        }
+
+        let selected_id = self.current_post().map(|post| post.full_id());
+        let list_len = self.list_len();
+        let threads = self.filtered_thread_view.as_ref().unwrap_or(&self.thread_view);
+        self.navigator.prev_post(&self.view_mode, list_len, threads, &self.collapsed_threads);
+        if self.current_post().map(|post| post.full_id()) == selected_id {
+            self.navigator.go_to_last(list_len);
+            self.status_message = Some("Wrapped around to the last match".to_string());
+        }
+        self.process_current_post_content();
+    }
+
+    /// Remove the filter, showing the full feed again
+    pub fn clear_filter(&mut self) {
+        self.mode = AppMode::Browsing;
+        self.filter_input.clear();
+        self.apply_filter();
+        self.status_message = Some("Filter cleared".to_string());
+    }
+
+    /// Rebuild the bookmarks view after the posts or bookmarks changed
+    fn update_bookmarked_posts(&mut self) {
+        self.bookmarked_posts = self.posts
+            .iter()
+            .filter(|post| self.bookmarks.contains(&post.full_id()))
+            .cloned()
+            .collect();
+    }
+
+    /// Rebuild the own posts view after the posts changed
+    fn update_own_posts(&mut self) {
+        self.own_posts = self.posts
+            .iter()
+            .filter(|post| self.is_own_post(post))
+            .cloned()
+            .collect();
+    }
+
+    /// Whether the post was written by the user
+    pub fn is_own_post(&self, post: &parser::Post) -> bool {
+        formatting::is_own_post(post, &self.user_nick, self.user_source.as_ref())
+    }
+
+    /// Keep the selection within the bookmarks or own posts view after it shrank
+    fn clamp_bookmark_selection(&mut self) {
+        let last = self.list_len().saturating_sub(1);
+        self.navigator.selected_post = self.navigator.selected_post.min(last);
+    }
+
+    /// Bookmark the current post, or remove its bookmark
+    pub fn toggle_bookmark(&mut self) {
+        let Some(full_id) = self.current_post().map(|post| post.full_id()) else {
+            return;
+        };
+
+        let added = self.bookmarks.insert(full_id.clone());
+        if !added {
+            self.bookmarks.remove(&full_id);
+        }
+        self.update_bookmarked_posts();
+        if self.view_mode == ViewMode::Bookmarks {
+            // The post disappears from the bookmarks view
+            self.clamp_bookmark_selection();
+            self.navigator.reset_scroll();
+            self.process_current_post_content();
+        }
+
+        self.status_message = Some(match post_sets::save_post_set(BOOKMARKS_FILE, &self.bookmarks) {
+            Err(e) => format!("Failed to save bookmarks: {e}"),
+            Ok(_) if added => "Bookmarked post".to_string(),
+            Ok(_) => "Removed bookmark".to_string(),
+        });
+    }
+
+    /// Number of notifications not viewed yet
+    pub fn unread_notification_count(&self) -> usize {
+        self.notification_feed.notifications
+            .iter()
+            .filter(|notification| !self.seen_notifications.contains(&notification.post.full_id()))
+            .count()
+    }
+
     /// Update cursor blink state if enough time has passed
     pub fn update_cursor_blink(&mut self) {
         let now = Instant::now();
@@ -518,12 +1914,21 @@ impl TUI {
         self.last_cursor_blink = Instant::now();
     }
 
+    /// Scroll the content pane just enough to show the row of the focused element
+    fn scroll_to_focused_element(&mut self) {
+        let (Some(focused), Some(content_area)) = (self.activatable_manager.focused_element(), self.content_area.get()) else {
+            return;
+        };
+        self.navigator.scroll_to_row(focused.line, content_area.height as usize, self.content_max_scroll.get());
+    }
+
     /// Navigate to the next activatable element in the current view
     pub fn next_hyperlink(&mut self) {
         // Update activatable manager from collector first
         self.activatable_manager.update_from_collector(&self.activatable_collector);
         
         if self.activatable_manager.focus_next() {
+            self.scroll_to_focused_element();
             if let Some(element) = self.activatable_manager.focused_element() {
                 match &element.element_type {
                     super::activatable::ActivatableType::Hyperlink { url, .. } => {
@@ -567,6 +1972,7 @@ impl TUI {
         self.activatable_manager.update_from_collector(&self.activatable_collector);
         
         if self.activatable_manager.focus_prev() {
+            self.scroll_to_focused_element();
             if let Some(element) = self.activatable_manager.focused_element() {
                 match &element.element_type {
                     super::activatable::ActivatableType::Hyperlink { url, .. } => {
@@ -609,12 +2015,11 @@ impl TUI {
         // Update activatable manager from collector first
         self.activatable_manager.update_from_collector(&self.activatable_collector);
 
-        if let Some(result_message) = self.activatable_manager.activate_focused(&self.view_mode) {
-            // Check if we need to start poll voting
-            if result_message == "StartPollVote" {
-                self.start_poll_vote();
-            } else {
-                self.status_message = Some(result_message);
+        if let Some(activation) = self.activatable_manager.activate_focused(&self.view_mode) {
+            match activation {
+                Activation::StartPollVote => self.start_poll_vote(),
+                Activation::OpenAuthor { url, username } => self.open_author_posts(url, username),
+                Activation::Message(message) => self.status_message = Some(message),
             }
             
             // If we activated a block, refresh the processed content
@@ -628,21 +2033,138 @@ impl TUI {
         }
     }
 
-    /// Count votes for the poll in the current post (only available in threaded view)
-    pub fn count_poll_votes(&mut self) {
-        // This functionality is only available in threaded view with access to ThreadNode
-        if self.view_mode != ViewMode::Threaded {
-            self.status_message = Some("Vote counting only available in threaded view (press 't' to switch)".to_string());
+    /// Select the post whose full ID or ID matches the command input, switching to the list view
+    pub fn jump_to_post(&mut self) {
+        self.mode = AppMode::Browsing;
+        let target = self.command_input.trim().to_string();
+        if target.is_empty() {
+            return;
+        }
+
+        let full_id = self.posts
+            .iter()
+            .find(|post| post.full_id() == target || post.id() == target)
+            .map(|post| post.full_id());
+        let Some(full_id) = full_id else {
+            self.status_message = Some("Post not found".to_string());
             return;
+        };
+
+        self.view_mode = ViewMode::List;
+        // A post hidden by the filter can only be selected without it
+        let hidden_by_filter = !self.visible_posts().iter().any(|post| post.full_id() == full_id);
+        if hidden_by_filter {
+            self.filter_input.clear();
+            self.update_filtered_posts();
+        }
+        self.select_post_by_full_id(&full_id);
+        self.process_current_post_content();
+        self.status_message = Some(if hidden_by_filter {
+            format!("Jumped to post {target}, filter cleared")
+        } else {
+            format!("Jumped to post {target}")
+        });
+    }
+
+    /// Show only the posts from the author's feed, fetching it when none of their posts are loaded
+    pub fn open_author_posts(&mut self, url: String, username: String) {
+        let author_posts: Vec<parser::Post> = self.posts
+            .iter()
+            .filter(|post| post.source().as_deref() == Some(url.as_str()))
+            .cloned()
+            .collect();
+
+        if author_posts.is_empty() {
+            self.status_message = Some(format!("Fetching {username}'s feed..."));
+            self.author_feed_request = Some((url, username));
+        } else {
+            self.show_author_posts(username, author_posts);
         }
+    }
+
+    /// Fetch the requested author's feed and show their posts
+    pub async fn fetch_author_feed(&mut self) {
+        let Some((url, username)) = self.author_feed_request.take() else {
+            return;
+        };
 
-        // Get the current post and thread node
-        let (current_post, thread_node) = match self.get_current_thread_node() {
-            Some((post, node)) => (post, node),
+        let fetch_options = FetchOptions::from_config(&self.config);
+        let feeds = remote::fetch_feeds(vec![(username.clone(), url.clone())], &fetch_options).await;
+        match feeds.into_iter().next().and_then(|fetched| fetched.result.ok()) {
+            Some((profile, mut posts)) if !posts.is_empty() => {
+                if !self.profiles.iter().any(|known| known.source() == profile.source()) {
+                    self.profiles.push(profile);
+                }
+                posts.sort_by_key(|post| std::cmp::Reverse(post.time()));
+                self.show_author_posts(username, posts);
+            }
+            Some(_) => {
+                self.status_message = Some(format!("{username} has no posts"));
+            }
             None => {
-                self.status_message = Some("No post selected".to_string());
-                return;
+                self.status_message = Some(format!("Failed to fetch {username}'s feed from {url}"));
             }
+        }
+    }
+
+    fn show_author_posts(&mut self, username: String, posts: Vec<parser::Post>) {
+        let saved_posts = std::mem::replace(&mut self.posts, posts);
+        let saved_threads = std::mem::take(&mut self.thread_view);
+        let saved_reactions = std::mem::take(&mut self.hidden_reactions);
+        let saved_muted = std::mem::take(&mut self.muted_posts);
+
+        // Keep the originally saved feed when jumping from one author to another
+        if self.author_view.is_none() {
+            self.author_view = Some(AuthorView {
+                username: username.clone(),
+                posts: saved_posts,
+                thread_view: saved_threads,
+                view_mode: self.view_mode.clone(),
+                selected_post: self.navigator.selected_post,
+                selected_thread: self.navigator.selected_thread,
+                selected_thread_post: self.navigator.selected_thread_post,
+                hidden_reactions: saved_reactions,
+                muted_posts: saved_muted,
+            });
+        } else if let Some(author_view) = &mut self.author_view {
+            author_view.username = username.clone();
+        }
+
+        self.view_mode = ViewMode::List;
+        self.navigator = Navigator::new();
+        self.apply_reaction_filter();
+        self.process_current_post_content();
+        self.status_message = Some(format!("Showing {} posts by {username} - press Esc to go back", self.posts.len()));
+    }
+
+    /// Go back from a single author's posts to the full feed
+    pub fn close_author_view(&mut self) {
+        let Some(author_view) = self.author_view.take() else {
+            return;
+        };
+
+        self.posts = author_view.posts;
+        self.thread_view = author_view.thread_view;
+        self.hidden_reactions = author_view.hidden_reactions;
+        self.muted_posts = author_view.muted_posts;
+        self.update_filtered_posts();
+        self.view_mode = author_view.view_mode;
+        self.navigator = Navigator::new();
+        self.navigator.selected_post = author_view.selected_post;
+        self.navigator.selected_thread = author_view.selected_thread;
+        self.navigator.selected_thread_post = author_view.selected_thread_post;
+        self.expand_selected_thread();
+        self.update_bookmarked_posts();
+        self.update_own_posts();
+        self.process_current_post_content();
+        self.status_message = Some("Back to the feed".to_string());
+    }
+
+    /// Count votes for the poll in the current post (only available in threaded view)
+    pub fn count_poll_votes(&mut self) {
+        let Some(current_post) = self.current_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return;
         };
 
         // Check if the current post has a poll
@@ -650,28 +2172,27 @@ impl TUI {
             self.status_message = Some("Current post does not contain a poll".to_string());
             return;
         }
+        let current_post = current_post.clone();
 
-        // Get all reply posts from the thread node to count votes
-        let reply_posts: Vec<parser::Post> = thread_node.replies
-            .iter()
-            .flat_map(|reply_node| {
-                let mut posts = vec![reply_node.post.clone()];
-                posts.extend(self.collect_all_replies_recursive(reply_node));
-                posts
-            })
-            .collect();
+        let thread_node = match self.get_current_thread_node() {
+            Some((_, thread_node)) if self.view_mode == ViewMode::Threaded => Some(thread_node),
+            _ => None,
+        };
+        let reply_posts = poll_replies(&current_post, thread_node, &self.posts, &self.hidden_reactions, &self.muted_posts);
 
         // Count the votes using the org-social-lib-rs poll module
-        match poll::count_poll_votes(current_post, &reply_posts) {
+        match poll::count_poll_votes(&current_post, &reply_posts) {
             Some(poll_result) => {
-                // Update the activatable manager with the poll results
+                // Update the activatable manager with the poll results,
+                // making sure it holds the current post's poll first
+                self.process_current_post_content();
                 self.activatable_manager.update_poll_results(&poll_result);
                 
                 // Display the poll results
                 let vote_summary = format!(
-                    "Poll Results: {} total votes, Status: {:?}",
+                    "Poll Results: {} total votes, Status: {}",
                     poll_result.total_votes,
-                    poll_result.status
+                    activatable::poll_status(&current_post)
                 );
                 
                 // You could also display individual option counts here
@@ -697,11 +2218,7 @@ impl TUI {
 
     /// Get the current thread node and post when in threaded view
     fn get_current_thread_node(&self) -> Option<(&parser::Post, &threading::ThreadNode)> {
-        if self.thread_view.is_empty() {
-            return None;
-        }
-
-        let current_thread = &self.thread_view.roots[self.navigator.selected_thread];
+        let current_thread = self.visible_threads().roots.get(self.navigator.selected_thread)?;
         let thread_posts = current_thread.flatten();
         let current_post = thread_posts.get(self.navigator.selected_thread_post)?;
         
@@ -710,16 +2227,6 @@ impl TUI {
         Some((current_post, current_thread))
     }
 
-    /// Recursively collect all reply posts from a thread node
-    fn collect_all_replies_recursive(&self, node: &threading::ThreadNode) -> Vec<parser::Post> {
-        let mut posts = Vec::new();
-        for reply in &node.replies {
-            posts.push(reply.post.clone());
-            posts.extend(self.collect_all_replies_recursive(reply));
-        }
-        posts
-    }
-
     /// Start poll voting mode for the currently focused poll
     pub fn start_poll_vote(&mut self) {
         // Update activatable manager from collector first
@@ -743,11 +2250,10 @@ impl TUI {
                     }
                 };
 
-                // Get the current post ID for creating the vote reply
-                let poll_post_id = if let Some(current_post) = self.current_post() {
-                    current_post.full_id()
-                } else {
-                    "unknown".to_string()
+                // The vote is a reply to the poll's post
+                let Some(poll_post_id) = self.current_post().map(|post| post.full_id()) else {
+                    self.status_message = Some("No poll focused".to_string());
+                    return;
                 };
 
                 self.poll_vote_state = Some(PollVoteState::new(
@@ -755,6 +2261,7 @@ impl TUI {
                     poll_options,
                     poll_post_id,
                 ));
+                self.poll_vote_origin = Some((self.view_mode.clone(), self.navigator.clone()));
                 self.mode = AppMode::PollVote;
                 self.status_message = Some("Select a poll option to vote for".to_string());
             }
@@ -804,9 +2311,274 @@ impl TUI {
             ));
         } else {
             // No option selected or no poll state, return to browsing mode
-            self.mode = AppMode::Browsing;
-            self.poll_vote_state = None;
+            self.cancel();
             self.status_message = Some("No option selected".to_string());
         }
     }
 }
+
+/// Find the post a reply is replying to among `posts`
+fn find_reply_parent<'a>(posts: &'a [parser::Post], post: &parser::Post) -> Option<&'a parser::Post> {
+    let reply_to = post.reply_to().as_deref()?;
+    let (base_url, reply_id) = formatting::split_reply_target(reply_to);
+    let candidates = || posts.iter().filter(|candidate| candidate.id() == reply_id);
+
+    // Prefer the post from the same feed, the user's own posts have a file path as their source
+    if reply_to.contains('#') {
+        let same_source = candidates().find(|candidate| {
+            candidate.source().as_deref().map(follows::normalize_url) == Some(follows::normalize_url(base_url))
+        });
+        if same_source.is_some() {
+            return same_source;
+        }
+    }
+    candidates().next()
+}
+
+/// Posts that may be votes of the poll: the replies in its thread when it is shown threaded,
+/// otherwise the posts replying to it among all posts, including hidden and muted ones
+fn poll_replies(
+    poll_post: &parser::Post,
+    thread_node: Option<&threading::ThreadNode>,
+    posts: &[parser::Post],
+    hidden_reactions: &[parser::Post],
+    muted_posts: &[parser::Post],
+) -> Vec<parser::Post> {
+    let poll_id = poll_post.full_id();
+    match thread_node {
+        // In the threaded view the votes are the replies in the poll's thread
+        Some(thread_node) => {
+            let mut reply_posts = collect_all_replies_recursive(thread_node);
+
+            // Hidden votes aren't in the threads, they are matched the same way threading resolves replies
+            reply_posts.extend(
+                hidden_reactions
+                    .iter()
+                    .filter(|post| {
+                        post.reply_to().as_deref().is_some_and(|reply_to| {
+                            reply_to == poll_id || (!reply_to.contains('#') && reply_to == poll_post.id())
+                        })
+                    })
+                    .cloned(),
+            );
+            reply_posts
+        }
+        // Elsewhere, look for posts replying to the poll among all posts
+        None => posts
+            .iter()
+            .chain(hidden_reactions)
+            .chain(muted_posts)
+            .filter(|post| find_reply_parent(posts, post).is_some_and(|parent| parent.full_id() == poll_id))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// All replies below the node, depth first
+fn collect_all_replies_recursive(node: &threading::ThreadNode) -> Vec<parser::Post> {
+    let mut posts = Vec::new();
+    for reply in &node.replies {
+        posts.push(reply.post.clone());
+        posts.extend(collect_all_replies_recursive(reply));
+    }
+    posts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{full_id, org_post, org_post_with_properties, parse_posts, SOURCE};
+
+    const POLL: &str = "2025-01-01T10:00:00+0000";
+
+    fn vote(id: &str, option: &str) -> String {
+        org_post_with_properties(id, &[("REPLY_TO", &full_id(POLL)), ("POLL_OPTION", option)], "")
+    }
+
+    fn poll_posts() -> Vec<parser::Post> {
+        parse_posts(SOURCE, &[
+            org_post_with_properties(POLL, &[("POLL_END", "2099-01-01T00:00:00+0000")], "Tabs or spaces?\n\n- [ ] Tabs\n- [ ] Spaces"),
+            org_post("2025-01-01T10:30:00+0000", None, "Unrelated"),
+            vote("2025-01-01T11:00:00+0000", "Tabs"),
+            org_post("2025-01-01T11:30:00+0000", Some(&full_id("2025-01-01T10:30:00+0000")), "Not a vote"),
+            vote("2025-01-01T12:00:00+0000", "Spaces"),
+            vote("2025-01-01T13:00:00+0000", "Spaces"),
+        ])
+    }
+
+    fn ids(posts: &[parser::Post]) -> Vec<&str> {
+        posts.iter().map(|post| post.id()).collect()
+    }
+
+    #[test]
+    fn poll_replies_in_list_order_are_the_posts_replying_to_the_poll() {
+        let posts = poll_posts();
+        let replies = poll_replies(&posts[0], None, &posts, &[], &[]);
+
+        assert_eq!(ids(&replies), vec!["2025-01-01T11:00:00+0000", "2025-01-01T12:00:00+0000", "2025-01-01T13:00:00+0000"]);
+        let result = poll::count_poll_votes(&posts[0], &replies).unwrap();
+        assert_eq!(result.total_votes, 3);
+        assert_eq!(result.options.iter().map(|option| option.votes).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn poll_replies_include_hidden_reactions() {
+        let mut posts = poll_posts();
+        let hidden_reactions = posts.split_off(4);
+
+        // Without threads the poll is still found among the visible posts
+        let replies = poll_replies(&posts[0], None, &posts, &hidden_reactions, &[]);
+        assert_eq!(ids(&replies), vec!["2025-01-01T11:00:00+0000", "2025-01-01T12:00:00+0000", "2025-01-01T13:00:00+0000"]);
+
+        // In the threaded view the hidden votes aren't in the poll's thread
+        let thread_view = threading::ThreadView::from_posts(posts.clone());
+        let thread = thread_view.roots.iter().find(|root| root.post.id() == POLL).unwrap();
+        let replies = poll_replies(&posts[0], Some(thread), &posts, &hidden_reactions, &[]);
+        assert_eq!(ids(&replies), vec!["2025-01-01T11:00:00+0000", "2025-01-01T12:00:00+0000", "2025-01-01T13:00:00+0000"]);
+        assert_eq!(poll::count_poll_votes(&posts[0], &replies).unwrap().total_votes, 3);
+    }
+
+    #[tokio::test]
+    async fn muted_author_can_be_unmuted_while_muted_posts_are_shown() {
+        let mut app = crate::test_support::tui(&[]).await;
+        app.posts = parse_posts("https://alice.example/social.org", &[
+            org_post("2025-01-01T10:00:00+0000", None, "First"),
+            org_post("2025-01-01T11:00:00+0000", None, "Second"),
+        ]);
+        app.reapply_hidden_posts(None);
+
+        app.toggle_mute_author();
+        assert!(app.posts.is_empty());
+        assert_eq!(app.muted_count(), 2);
+        assert!(app.current_post().is_none());
+
+        app.toggle_show_muted();
+        assert!(app.status_message.as_deref().unwrap().contains("press m on one to unmute"));
+        app.toggle_mute_author();
+        assert!(app.status_message.as_deref().unwrap().starts_with("Unmuted"));
+        assert!(app.muted_authors.is_empty());
+
+        app.toggle_show_muted();
+        assert_eq!(app.posts.len(), 2);
+        assert_eq!(app.muted_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn draft_is_saved_once_typing_pauses() {
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut app = crate::test_support::tui(&[]).await;
+        let path = drafts::new_post_draft_path().unwrap();
+
+        app.start_new_post();
+        for c in "Hello".chars() {
+            app.handle_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+        app.save_if_idle();
+        assert!(!path.exists());
+
+        // Every change starts the pause over
+        app.draft_changed_at = Some(Instant::now() - SAVE_DELAY);
+        app.handle_event(KeyEvent::from(KeyCode::Char('!')));
+        app.save_if_idle();
+        assert!(!path.exists());
+
+        app.draft_changed_at = Some(Instant::now() - SAVE_DELAY);
+        app.save_if_idle();
+        assert_eq!(drafts::load_draft(&path).unwrap().content, "Hello!");
+        assert!(app.draft_changed_at.is_none());
+
+        // Moving the cursor doesn't change the draft
+        app.handle_event(KeyEvent::from(KeyCode::Left));
+        assert!(app.draft_changed_at.is_none());
+        drafts::remove_draft(&path);
+    }
+
+    #[tokio::test]
+    async fn post_cap_counts_the_filtered_posts() {
+        let tagged = |id: &str, tags: &str| org_post_with_properties(id, &[("TAGS", tags)], "Post");
+        let (profile, posts) = crate::test_support::parse_social_file(SOURCE, &[
+            tagged("2025-01-01T10:00:00+0000", "rust"),
+            tagged("2025-01-01T11:00:00+0000", "emacs"),
+            tagged("2025-01-01T12:00:00+0000", "rust"),
+            tagged("2025-01-01T13:00:00+0000", "emacs"),
+            tagged("2025-01-01T14:00:00+0000", "rust"),
+        ]);
+        let filters = PostFilters::new(None, None, vec!["rust".to_string()]);
+        let fetch_options = FetchOptions::from_config(&Config::default());
+
+        let loaded = TUI::load_feed(&profile, posts, true, &filters, &fetch_options, 2).await;
+        assert_eq!(ids(&loaded.posts), ["2025-01-01T14:00:00+0000", "2025-01-01T12:00:00+0000"]);
+    }
+
+    #[tokio::test]
+    async fn jump_selects_the_post_while_a_filter_is_locked() {
+        let mut app = crate::test_support::tui(&[
+            org_post("2025-01-01T10:00:00+0000", None, "About rust"),
+            org_post("2025-01-01T11:00:00+0000", None, "About emacs"),
+            org_post("2025-01-01T12:00:00+0000", None, "More rust"),
+            org_post("2025-01-01T13:00:00+0000", None, "More emacs"),
+        ]).await;
+        app.filter_input = "rust".to_string();
+        app.apply_filter();
+        app.lock_filter();
+
+        app.command_input = "2025-01-01T10:00:00+0000".to_string();
+        app.jump_to_post();
+        assert_eq!(app.current_post().unwrap().id(), "2025-01-01T10:00:00+0000");
+        assert_eq!(app.active_filter(), Some("rust"));
+
+        app.command_input = "2025-01-01T11:00:00+0000".to_string();
+        app.jump_to_post();
+        assert_eq!(app.current_post().unwrap().id(), "2025-01-01T11:00:00+0000");
+        assert_eq!(app.active_filter(), None);
+    }
+
+    #[tokio::test]
+    async fn read_posts_are_saved_once_browsing_pauses() {
+        let mut app = crate::test_support::tui(&[
+            org_post("2025-01-01T10:00:00+0000", None, "First"),
+            org_post("2025-01-01T11:00:00+0000", None, "Second"),
+        ]).await;
+        let path = Config::get_config_dir().unwrap().join(READ_POSTS_FILE);
+
+        for selected in [0, 1, 0, 1] {
+            app.navigator.selected_post = selected;
+            app.process_current_post_content();
+        }
+        app.save_if_idle();
+        assert!(!path.exists());
+
+        app.read_posts_changed_at = Some(Instant::now() - SAVE_DELAY);
+        app.save_if_idle();
+        let saved = post_sets::load_post_set(READ_POSTS_FILE);
+        assert!(saved.contains(&full_id("2025-01-01T10:00:00+0000")));
+        assert!(saved.contains(&full_id("2025-01-01T11:00:00+0000")));
+        assert!(app.read_posts_changed_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn rebound_keys_win_over_the_match_keys_while_filtering() {
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut app = crate::test_support::tui(&[
+            org_post("2025-01-01T10:00:00+0000", None, "About rust"),
+            org_post("2025-01-01T11:00:00+0000", None, "More rust"),
+        ]).await;
+        app.filter_input = "rust".to_string();
+        app.apply_filter();
+        app.lock_filter();
+        assert!(app.status_message.as_deref().unwrap().contains("n/N: next/previous match"));
+        app.handle_event(KeyEvent::from(KeyCode::Char('n')));
+        assert!(app.mode == AppMode::Browsing);
+
+        let bindings = [("new_post", "n"), ("next_match", "]"), ("prev_match", "[")];
+        let (keybindings, warnings) = KeyBindings::from_config(&bindings.iter().map(|(action, key)| (action.to_string(), key.to_string())).collect());
+        assert!(warnings.is_empty());
+        app.keybindings = keybindings;
+        app.lock_filter();
+        assert!(app.status_message.as_deref().unwrap().contains("]/[: next/previous match, Esc: clear"));
+        app.handle_event(KeyEvent::from(KeyCode::Char('n')));
+        assert!(app.mode == AppMode::NewPost);
+    }
+}
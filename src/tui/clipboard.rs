@@ -0,0 +1,35 @@
+//! System clipboard access for the TUI.
+
+use arboard::Clipboard;
+
+/// Lazily opened handle to the system clipboard.
+///
+/// The handle is kept open for the whole session, since on Linux the copied
+/// contents are only available while the clipboard instance is alive.
+#[derive(Default)]
+pub struct ClipboardHandle {
+    clipboard: Option<Clipboard>,
+}
+
+impl std::fmt::Debug for ClipboardHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipboardHandle")
+            .field("open", &self.clipboard.is_some())
+            .finish()
+    }
+}
+
+impl ClipboardHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy text to the system clipboard, opening it on first use
+    pub fn copy(&mut self, text: &str) -> Result<(), arboard::Error> {
+        let clipboard = match &mut self.clipboard {
+            Some(clipboard) => clipboard,
+            None => self.clipboard.insert(Clipboard::new()?),
+        };
+        clipboard.set_text(text)
+    }
+}
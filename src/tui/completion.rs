@@ -0,0 +1,64 @@
+//! Completion of followed nicks typed after `@` in the content of posts and replies.
+
+/// Most completions listed at once
+pub const MAX_COMPLETIONS: usize = 6;
+
+/// Nicks matching the `@prefix` before the cursor
+#[derive(Debug, Clone, PartialEq)]
+pub struct NickCompletion {
+    /// Byte offset of the `@` in the content
+    pub start: usize,
+    pub prefix: String,
+    /// Matching follows as `(nick, url)`
+    pub matches: Vec<(String, String)>,
+    pub selected: usize,
+}
+
+impl NickCompletion {
+    /// Completion for the content and cursor, None when no `@nick` is being typed or nothing matches
+    pub fn find(follows: &[(String, String)], content: &str, cursor: usize) -> Option<Self> {
+        let (start, prefix) = mention_prefix(content, cursor)?;
+        let prefix = prefix.to_lowercase();
+        let matches: Vec<(String, String)> = follows
+            .iter()
+            .filter(|(nick, _)| nick.to_lowercase().starts_with(&prefix))
+            .take(MAX_COMPLETIONS)
+            .cloned()
+            .collect();
+        if matches.is_empty() {
+            return None;
+        }
+        Some(Self { start, prefix, matches, selected: 0 })
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.matches.len();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = (self.selected + self.matches.len() - 1) % self.matches.len();
+    }
+
+    /// Mention of the selected follow, as written in the social file
+    pub fn selected_mention(&self) -> String {
+        let (nick, url) = &self.matches[self.selected];
+        format!("[[org-social:{url}][@{nick}]]")
+    }
+}
+
+/// Characters a nick is made of, anything else ends the `@nick` being typed
+fn is_nick_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+/// Start of the `@` and the nick typed after it when the cursor is right after `@nick`.
+/// The `@` has to start a word, so e-mail addresses and written mentions are left alone.
+fn mention_prefix(content: &str, cursor: usize) -> Option<(usize, &str)> {
+    let before = content.get(..cursor)?;
+    let word_start = before.rfind(|c| !is_nick_char(c))?;
+    if !before[word_start..].starts_with('@') {
+        return None;
+    }
+    let at_word_start = before[..word_start].chars().next_back().is_none_or(char::is_whitespace);
+    at_word_start.then(|| (word_start, &before[word_start + 1..]))
+}
@@ -0,0 +1,57 @@
+//! Desktop notifications for mentions and replies found by refreshing the feed.
+//!
+//! Sent through the notification tool of the OS (`notify-send` on Linux, `osascript` on macOS),
+//! as notify-rust couldn't be added to the dependencies. Without the tool nothing is shown.
+
+use crate::formatting;
+use org_social_lib_rs::notifications::{Notification, NotificationType};
+use std::process::{Command, Stdio};
+
+/// Longest preview of a post shown in a notification, in characters
+const BODY_CHARS: usize = 120;
+
+/// Title and text of the desktop notification for new notifications, None when there are none
+pub fn notification_text(new: &[&Notification]) -> Option<(String, String)> {
+    match new {
+        [] => None,
+        [notification] => {
+            let author = notification.post.author().clone().unwrap_or_else(|| "unknown".to_string());
+            let title = match notification.notification_type {
+                NotificationType::Mention => format!("{author} mentioned you"),
+                NotificationType::Reply => format!("{author} replied to you"),
+                NotificationType::MentionAndReply => format!("{author} replied and mentioned you"),
+            };
+            let body = notification.post.content().trim().replace('\n', " ");
+            Some((title, formatting::truncate_chars(&body, BODY_CHARS)))
+        }
+        _ => {
+            let mut authors: Vec<String> = new.iter().filter_map(|notification| notification.post.author().clone()).collect();
+            authors.dedup();
+            Some((format!("{} new notifications", new.len()), format!("From {}", authors.join(", "))))
+        }
+    }
+}
+
+/// Show a desktop notification, without waiting for it to be shown
+pub fn send(title: &str, body: &str) -> std::io::Result<()> {
+    let mut command = if cfg!(target_os = "linux") {
+        let mut command = Command::new("notify-send");
+        command.args(["--app-name=org-social-rs", title, body]);
+        command
+    } else if cfg!(target_os = "macos") {
+        let mut command = Command::new("osascript");
+        command.args(["-e", &format!("display notification {} with title {}", applescript_string(body), applescript_string(title))]);
+        command
+    } else {
+        return Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Unsupported OS"));
+    };
+    let mut child = command.stdout(Stdio::null()).stderr(Stdio::null()).spawn()?;
+    // Reap the tool once it exits, so it doesn't linger as a zombie until the TUI quits
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Quote text for an AppleScript string literal
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,139 @@
+//! Draft persistence for posts and replies written in the TUI.
+//!
+//! Drafts are stored as TOML files in the `drafts` subdirectory of the config
+//! directory, so unfinished posts survive the TUI being closed or crashing.
+
+use crate::config::Config;
+use org_social_lib_rs::{new_post::NewPostState, reply::ReplyState};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Saved contents of a post or reply editor
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Draft {
+    pub content: String,
+    pub tags: Vec<String>,
+    pub tags_input: String,
+    pub mood: String,
+    pub lang: String,
+    pub poll_end: String,
+    pub poll_option: String,
+}
+
+impl Draft {
+    pub fn from_new_post(state: &NewPostState) -> Self {
+        Self {
+            content: state.content.clone(),
+            tags: state.tags.clone(),
+            tags_input: state.tags_input.clone(),
+            mood: state.mood.clone(),
+            lang: state.lang.clone(),
+            poll_end: state.poll_end.clone(),
+            poll_option: state.poll_option.clone(),
+        }
+    }
+
+    pub fn from_reply(state: &ReplyState) -> Self {
+        Self {
+            content: state.content.clone(),
+            tags: state.tags.clone(),
+            tags_input: state.tags_input.clone(),
+            mood: state.mood.clone(),
+            poll_option: state.poll_option.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Restore the draft into a new post editor, placing cursors at the end of each field
+    pub fn apply_to_new_post(self, state: &mut NewPostState) {
+        state.content_cursor = self.content.len();
+        state.tags_input_cursor = self.tags_input.len();
+        state.mood_cursor = self.mood.len();
+        state.lang_cursor = self.lang.len();
+        state.poll_end_cursor = self.poll_end.len();
+        state.poll_option_cursor = self.poll_option.len();
+        state.content = self.content;
+        state.tags = self.tags;
+        state.tags_input = self.tags_input;
+        state.mood = self.mood;
+        state.lang = self.lang;
+        state.poll_end = self.poll_end;
+        state.poll_option = self.poll_option;
+    }
+
+    /// Restore the draft into a reply editor, placing cursors at the end of each field
+    pub fn apply_to_reply(self, state: &mut ReplyState) {
+        state.content_cursor = self.content.len();
+        state.tags_input_cursor = self.tags_input.len();
+        state.mood_cursor = self.mood.len();
+        state.poll_option_cursor = self.poll_option.len();
+        state.content = self.content;
+        state.tags = self.tags;
+        state.tags_input = self.tags_input;
+        state.mood = self.mood;
+        state.poll_option = self.poll_option;
+    }
+
+    /// Check if there is nothing worth keeping in the draft.
+    /// Tags alone don't count, since replies start with the parent's tags.
+    pub fn is_empty(&self) -> bool {
+        self.content.trim().is_empty()
+            && self.tags_input.trim().is_empty()
+            && self.mood.is_empty()
+            && self.lang.is_empty()
+            && self.poll_end.is_empty()
+            && self.poll_option.is_empty()
+    }
+}
+
+/// Directory of the drafts, created by the first draft saved to it
+fn drafts_dir() -> Option<PathBuf> {
+    Some(Config::get_config_dir().ok()?.join("drafts"))
+}
+
+/// Path of the draft for a new post
+pub fn new_post_draft_path() -> Option<PathBuf> {
+    Some(drafts_dir()?.join("new_post.toml"))
+}
+
+/// Path of the draft for a reply to the post with the given full ID
+pub fn reply_draft_path(target_id: &str) -> Option<PathBuf> {
+    let name: String = target_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    Some(drafts_dir()?.join(format!("reply_{name}.toml")))
+}
+
+/// Save the draft, empty drafts remove the file instead
+pub fn save_draft(path: &Path, draft: &Draft) -> Result<(), Box<dyn std::error::Error>> {
+    if draft.is_empty() {
+        remove_draft(path);
+        return Ok(());
+    }
+    let contents = toml::to_string_pretty(draft)?;
+    match std::fs::write(path, &contents) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(path, contents)?;
+        }
+        result => result?,
+    }
+    Ok(())
+}
+
+/// Load a draft, missing or corrupted files are ignored
+pub fn load_draft(path: &Path) -> Option<Draft> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    toml::from_str::<Draft>(&contents)
+        .ok()
+        .filter(|draft| !draft.is_empty())
+}
+
+/// Remove a draft, e.g. after it was submitted
+pub fn remove_draft(path: &Path) {
+    let _ = std::fs::remove_file(path);
+}
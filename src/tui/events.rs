@@ -1,7 +1,8 @@
 //! Event handling and input processing.
 
+use super::keybindings::{Action, KeyBindings};
 use super::modes::AppMode;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use org_social_lib_rs::{new_post, reply};
 
 /// Handle keyboard events and return appropriate actions
@@ -37,23 +38,86 @@ pub enum EventResult {
     PrevLink,
     ActivateLink,
     CountPollVotes,
+    ToggleBookmark,
+    MarkAllRead,
+    CopyContent,
+    CopyPostUrl,
+    ToggleRawView,
+    ToggleCollapseThread,
+    ToggleAllBlocks,
+    ToggleReactions,
+    ToggleMuteAuthor,
+    ToggleShowMuted,
+    ToggleFocusMode,
+    CycleNotificationFilter,
+    ShowProfile,
+    StartFilter,
+    FilterInput(char),
+    FilterBackspace,
+    LockFilter,
+    ClearFilter,
     StartPollVote,
     PollVoteUp,
     PollVoteDown,
     SubmitPollVote,
+    ClickAt { column: u16, row: u16 },
+    Refresh,
+    OpenExternalEditor,
+    Undo,
+    Redo,
+    WordLeft,
+    WordRight,
+    DeleteWordBack,
+    StartCommand,
+    CommandInput(char),
+    CommandBackspace,
+    SubmitCommand,
+    DiscardDraft,
+    KeepEditing,
+    SaveDraft,
+    AcceptCompletion,
+    NextCompletion,
+    PrevCompletion,
+    DismissCompletion,
+    TogglePreview,
+    NextMatch,
+    PrevMatch,
 }
 
-pub fn handle_key_event(key: KeyEvent, mode: &AppMode) -> EventResult {
+pub fn handle_key_event(key: KeyEvent, mode: &AppMode, keybindings: &KeyBindings) -> EventResult {
     match mode {
-        AppMode::Browsing => handle_browsing_input(key),
+        AppMode::Browsing => handle_browsing_input(key, keybindings),
         AppMode::Reply => handle_reply_input(key),
         AppMode::NewPost => handle_new_post_input(key),
         AppMode::Help => handle_help_input(key),
         AppMode::PollVote => handle_poll_vote_input(key),
+        AppMode::Command => handle_command_input(key),
+        AppMode::Profile => handle_profile_input(key),
+        AppMode::Filter => handle_filter_input(key),
+        AppMode::ConfirmDiscard => handle_confirm_discard_input(key),
     }
 }
 
-fn handle_browsing_input(key: KeyEvent) -> EventResult {
+/// Handle mouse events, only used while browsing
+pub fn handle_mouse_event(mouse: MouseEvent, mode: &AppMode) -> EventResult {
+    if *mode != AppMode::Browsing {
+        return EventResult::Continue;
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => EventResult::ClickAt { column: mouse.column, row: mouse.row },
+        MouseEventKind::ScrollDown => EventResult::ScrollDown,
+        MouseEventKind::ScrollUp => EventResult::ScrollUp,
+        _ => EventResult::Continue,
+    }
+}
+
+fn handle_browsing_input(key: KeyEvent, keybindings: &KeyBindings) -> EventResult {
+    // User configured keybindings take precedence over the defaults
+    if let Some(action) = keybindings.action_for(&key) {
+        return action.to_event_result();
+    }
+
     match key.code {
         KeyCode::Char('q') => EventResult::Quit,
         KeyCode::Char('j') | KeyCode::Down => EventResult::NextPost,
@@ -65,8 +129,24 @@ fn handle_browsing_input(key: KeyEvent) -> EventResult {
         KeyCode::Char('t') => EventResult::ToggleView,
         KeyCode::Char('r') => EventResult::StartReply,
         KeyCode::Char('n') => EventResult::StartNewPost,
+        KeyCode::Char('R') => EventResult::Refresh,
         KeyCode::Char('h') | KeyCode::Char('?') => EventResult::ToggleHelp,
+        KeyCode::Char(':') => EventResult::StartCommand,
+        KeyCode::Char('/') => EventResult::StartFilter,
         KeyCode::Char('v') => EventResult::CountPollVotes, // Count votes for poll in current post
+        KeyCode::Char('b') => EventResult::ToggleBookmark,
+        KeyCode::Char('M') => EventResult::MarkAllRead,
+        KeyCode::Char('y') => EventResult::CopyContent,
+        KeyCode::Char('Y') => EventResult::CopyPostUrl,
+        KeyCode::Char('o') => EventResult::ToggleRawView,
+        KeyCode::Char('c') => EventResult::ToggleCollapseThread,
+        KeyCode::Char('z') => EventResult::ToggleAllBlocks,
+        KeyCode::Char('H') => EventResult::ToggleReactions,
+        KeyCode::Char('m') => EventResult::ToggleMuteAuthor,
+        KeyCode::Char('U') => EventResult::ToggleShowMuted,
+        KeyCode::Char('f') => EventResult::ToggleFocusMode,
+        KeyCode::Char('T') => EventResult::CycleNotificationFilter,
+        KeyCode::Char('p') => EventResult::ShowProfile,
         KeyCode::Char('l') => EventResult::NextLink,      // Navigate to next activatable element
         KeyCode::Char('L') => EventResult::PrevLink,      // Navigate to previous activatable element
         KeyCode::Enter | KeyCode::Tab => EventResult::ActivateLink, // Activate focused element (link or block)
@@ -81,11 +161,21 @@ fn handle_reply_input(key: KeyEvent) -> EventResult {
             // Handle Ctrl+S for submission
             if c == 's' && key.modifiers.contains(KeyModifiers::CONTROL) {
                 EventResult::SubmitReply
+            } else if c == 'e' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::OpenExternalEditor
+            } else if c == 'z' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::Undo
+            } else if c == 'y' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::Redo
+            } else if c == 'w' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::DeleteWordBack
             } else {
                 EventResult::ReplyInput(c)
             }
         }
         KeyCode::Backspace => EventResult::ReplyBackspace,
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::WordLeft,
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::WordRight,
         KeyCode::Enter => {
             // Handle different Enter key combinations
             if key.modifiers.contains(KeyModifiers::SHIFT) {
@@ -110,11 +200,23 @@ fn handle_new_post_input(key: KeyEvent) -> EventResult {
             // Handle Ctrl+S for submission
             if c == 's' && key.modifiers.contains(KeyModifiers::CONTROL) {
                 EventResult::SubmitNewPost
+            } else if c == 'e' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::OpenExternalEditor
+            } else if c == 'z' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::Undo
+            } else if c == 'y' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::Redo
+            } else if c == 'w' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::DeleteWordBack
+            } else if c == 'p' && key.modifiers.contains(KeyModifiers::CONTROL) {
+                EventResult::TogglePreview
             } else {
                 EventResult::NewPostInput(c)
             }
         }
         KeyCode::Backspace => EventResult::NewPostBackspace,
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::WordLeft,
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::WordRight,
         KeyCode::Enter => {
             // Handle different Enter key combinations
             if key.modifiers.contains(KeyModifiers::SHIFT) {
@@ -133,6 +235,16 @@ fn handle_new_post_input(key: KeyEvent) -> EventResult {
     }
 }
 
+fn handle_command_input(key: KeyEvent) -> EventResult {
+    match key.code {
+        KeyCode::Char(c) => EventResult::CommandInput(c),
+        KeyCode::Backspace => EventResult::CommandBackspace,
+        KeyCode::Enter => EventResult::SubmitCommand,
+        KeyCode::Esc => EventResult::Cancel,
+        _ => EventResult::Continue,
+    }
+}
+
 fn handle_help_input(key: KeyEvent) -> EventResult {
     match key.code {
         KeyCode::Char('h') | KeyCode::Char('?') | KeyCode::Esc => EventResult::ToggleHelp,
@@ -144,6 +256,61 @@ fn handle_help_input(key: KeyEvent) -> EventResult {
     }
 }
 
+fn handle_filter_input(key: KeyEvent) -> EventResult {
+    match key.code {
+        KeyCode::Char(c) => EventResult::FilterInput(c),
+        KeyCode::Backspace => EventResult::FilterBackspace,
+        KeyCode::Enter => EventResult::LockFilter,
+        KeyCode::Esc => EventResult::ClearFilter,
+        _ => EventResult::Continue,
+    }
+}
+
+fn handle_profile_input(key: KeyEvent) -> EventResult {
+    match key.code {
+        KeyCode::Char('p') | KeyCode::Char('q') | KeyCode::Esc => EventResult::Cancel,
+        _ => EventResult::Continue,
+    }
+}
+
+fn handle_confirm_discard_input(key: KeyEvent) -> EventResult {
+    // Ignore Ctrl+D and the like, discarding should be deliberate
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return EventResult::Continue;
+    }
+    match key.code {
+        KeyCode::Char('d') => EventResult::DiscardDraft,
+        KeyCode::Char('s') => EventResult::SaveDraft,
+        KeyCode::Char('k') | KeyCode::Esc => EventResult::KeepEditing,
+        _ => EventResult::Continue,
+    }
+}
+
+/// Keys handled by the nick completion popup, None for keys that go to the editor
+pub fn handle_completion_input(key: KeyEvent) -> Option<EventResult> {
+    match key.code {
+        KeyCode::Tab => Some(EventResult::AcceptCompletion),
+        KeyCode::Enter if !key.modifiers.contains(KeyModifiers::SHIFT) => Some(EventResult::AcceptCompletion),
+        KeyCode::Down => Some(EventResult::NextCompletion),
+        KeyCode::Up => Some(EventResult::PrevCompletion),
+        KeyCode::Esc => Some(EventResult::DismissCompletion),
+        _ => None,
+    }
+}
+
+/// Keys jumping between filter matches while a filter is applied, None for keys browsing as usual.
+/// `n` and `N` only do so while neither they nor the match actions are bound in `[keybindings]`.
+pub fn handle_match_input(key: KeyEvent, keybindings: &KeyBindings) -> Option<EventResult> {
+    if keybindings.action_for(&key).is_some() {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('n') if keybindings.key_name(Action::NextMatch).is_none() => Some(EventResult::NextMatch),
+        KeyCode::Char('N') if keybindings.key_name(Action::PrevMatch).is_none() => Some(EventResult::PrevMatch),
+        _ => None,
+    }
+}
+
 /// Handle Enter key behavior in reply mode based on current field
 pub fn handle_reply_enter(reply_state: &Option<reply::ReplyState>) -> EventResult {
     match reply_state.as_ref().map(|rs| &rs.current_field) {
@@ -0,0 +1,40 @@
+//! Editing post content in an external editor.
+
+use std::io;
+use std::process::Command;
+
+/// Editor command from `$EDITOR`, if it is set
+pub fn editor_command() -> Option<String> {
+    std::env::var("EDITOR")
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+}
+
+/// Open the text in the editor and return the edited text.
+///
+/// The editor command may contain arguments (e.g. `code --wait`),
+/// the path of a temporary file seeded with the text is appended to them.
+/// The terminal has to be handed over to the editor before calling this.
+pub fn edit_text(editor: &str, text: &str) -> io::Result<String> {
+    let path = std::env::temp_dir().join(format!("org-social-rs-{}.org", std::process::id()));
+    std::fs::write(&path, text)?;
+
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or_default();
+    let result = Command::new(program)
+        .args(parts)
+        .arg(&path)
+        .status()
+        .and_then(|status| {
+            if status.success() {
+                std::fs::read_to_string(&path)
+            } else {
+                Err(io::Error::other(format!("{program} exited with {status}")))
+            }
+        });
+
+    let _ = std::fs::remove_file(&path);
+
+    // Editors usually leave a trailing newline, which isn't part of the post
+    result.map(|edited| edited.trim_end_matches('\n').to_string())
+}
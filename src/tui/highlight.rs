@@ -0,0 +1,155 @@
+//! Syntax highlighting of `src` blocks in the content pane.
+//!
+//! Lines are highlighted one at a time with a small table of languages: keywords, strings,
+//! numbers and line comments. The text of a line is never changed, so columns stay valid.
+//! syntect, with its full grammars and a cached syntax set, couldn't be added to the dependencies
+//! when this was written, so languages missing from the table are shown without highlighting.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// Keywords and comment syntax of a language
+pub struct Syntax {
+    /// Names used after `#+begin_src`
+    names: &'static [&'static str],
+    keywords: &'static [&'static str],
+    line_comment: &'static str,
+    /// Whether `'` starts a string, it doesn't in Rust (lifetimes) or Lisp (quoting)
+    single_quote_strings: bool,
+}
+
+/// Supported languages, built once instead of per frame
+const SYNTAXES: &[Syntax] = &[
+    Syntax {
+        names: &["rust", "rs"],
+        keywords: &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+            "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+            "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+        ],
+        line_comment: "//",
+        single_quote_strings: false,
+    },
+    Syntax {
+        names: &["python", "py"],
+        keywords: &[
+            "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+            "except", "False", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "None",
+            "nonlocal", "not", "or", "pass", "raise", "return", "True", "try", "while", "with", "yield",
+        ],
+        line_comment: "#",
+        single_quote_strings: true,
+    },
+    Syntax {
+        names: &["javascript", "js", "typescript", "ts"],
+        keywords: &[
+            "async", "await", "break", "case", "catch", "class", "const", "continue", "default", "delete", "do",
+            "else", "export", "extends", "false", "finally", "for", "function", "if", "import", "in", "instanceof",
+            "interface", "let", "new", "null", "return", "switch", "this", "throw", "true", "try", "type", "typeof",
+            "undefined", "var", "while", "yield",
+        ],
+        line_comment: "//",
+        single_quote_strings: true,
+    },
+    Syntax {
+        names: &["sh", "bash", "shell", "zsh"],
+        keywords: &[
+            "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in", "local",
+            "return", "then", "until", "while",
+        ],
+        line_comment: "#",
+        single_quote_strings: true,
+    },
+    Syntax {
+        names: &["c", "cpp", "c++", "java", "go"],
+        keywords: &[
+            "break", "case", "char", "class", "const", "continue", "default", "do", "double", "else", "enum",
+            "false", "float", "for", "func", "if", "import", "int", "long", "new", "package", "private", "public",
+            "return", "static", "struct", "switch", "true", "void", "while",
+        ],
+        line_comment: "//",
+        single_quote_strings: true,
+    },
+    Syntax {
+        names: &["emacs-lisp", "elisp", "lisp", "scheme", "clojure"],
+        keywords: &[
+            "cond", "defcustom", "defmacro", "defn", "defun", "defvar", "define", "if", "interactive", "lambda",
+            "let", "nil", "progn", "setq", "unless", "when",
+        ],
+        line_comment: ";",
+        single_quote_strings: false,
+    },
+];
+
+/// Syntax for the language named after `#+begin_src`, None for unknown languages
+pub fn find_syntax(language: &str) -> Option<&'static Syntax> {
+    let language = language.to_lowercase();
+    SYNTAXES.iter().find(|syntax| syntax.names.contains(&language.as_str()))
+}
+
+/// Split a line of code into styled spans
+pub fn highlight_line(line: &str, syntax: &Syntax) -> Vec<Span<'static>> {
+    let keyword_style = Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD);
+    let string_style = Style::default().fg(Color::Green);
+    let number_style = Style::default().fg(Color::Yellow);
+    let comment_style = Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC);
+
+    let mut spans = vec![];
+    let mut plain = String::new();
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        let (token, style) = if rest.starts_with(syntax.line_comment) {
+            (rest, comment_style)
+        } else if c == '"' || (c == '\'' && syntax.single_quote_strings) {
+            (&rest[..string_end(rest, c)], string_style)
+        } else if is_word_char(c) {
+            let word = &rest[..rest.find(|c| !is_word_char(c)).unwrap_or(rest.len())];
+            if c.is_ascii_digit() {
+                (word, number_style)
+            } else if syntax.keywords.contains(&word) {
+                (word, keyword_style)
+            } else {
+                plain.push_str(word);
+                rest = &rest[word.len()..];
+                continue;
+            }
+        } else {
+            plain.push(c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        };
+
+        if !plain.is_empty() {
+            spans.push(Span::raw(std::mem::take(&mut plain)));
+        }
+        spans.push(Span::styled(token.to_string(), style));
+        rest = &rest[token.len()..];
+    }
+
+    if !plain.is_empty() {
+        spans.push(Span::raw(plain));
+    }
+    spans
+}
+
+/// Characters making up identifiers, keywords and numbers
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Byte length of the string starting at the quote, up to the closing quote or the end of the line
+fn string_end(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if c == quote => return i + c.len_utf8(),
+            _ => {}
+        }
+    }
+    text.len()
+}
@@ -0,0 +1,237 @@
+//! User configurable keybindings for browsing mode.
+//!
+//! Bindings come from the `[keybindings]` config table, mapping action names to key strings
+//! like `"j"`, `"C-n"`, `"M-v"`, `"PageDown"` or `"Enter"`. Configured bindings are checked
+//! before the built-in defaults, so unset actions keep their default keys.
+
+use super::events::EventResult;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+/// Browsing actions that can be bound to keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    NextPost,
+    PrevPost,
+    ScrollDown,
+    ScrollUp,
+    GoToFirst,
+    GoToLast,
+    ToggleView,
+    Reply,
+    NewPost,
+    Refresh,
+    ToggleHelp,
+    CountPollVotes,
+    ToggleBookmark,
+    MarkAllRead,
+    CopyContent,
+    CopyPostUrl,
+    ToggleRawView,
+    ToggleCollapseThread,
+    ToggleAllBlocks,
+    ToggleReactions,
+    ToggleMuteAuthor,
+    ToggleShowMuted,
+    ToggleFocusMode,
+    CycleNotificationFilter,
+    ShowProfile,
+    Filter,
+    NextMatch,
+    PrevMatch,
+    NextLink,
+    PrevLink,
+    ActivateLink,
+}
+
+impl Action {
+    const ALL: [(&'static str, Action); 32] = [
+        ("quit", Action::Quit),
+        ("next_post", Action::NextPost),
+        ("prev_post", Action::PrevPost),
+        ("scroll_down", Action::ScrollDown),
+        ("scroll_up", Action::ScrollUp),
+        ("go_to_first", Action::GoToFirst),
+        ("go_to_last", Action::GoToLast),
+        ("toggle_view", Action::ToggleView),
+        ("reply", Action::Reply),
+        ("new_post", Action::NewPost),
+        ("refresh", Action::Refresh),
+        ("help", Action::ToggleHelp),
+        ("count_poll_votes", Action::CountPollVotes),
+        ("toggle_bookmark", Action::ToggleBookmark),
+        ("mark_all_read", Action::MarkAllRead),
+        ("copy_content", Action::CopyContent),
+        ("copy_post_url", Action::CopyPostUrl),
+        ("toggle_raw_view", Action::ToggleRawView),
+        ("toggle_collapse_thread", Action::ToggleCollapseThread),
+        ("toggle_all_blocks", Action::ToggleAllBlocks),
+        ("toggle_reactions", Action::ToggleReactions),
+        ("toggle_mute_author", Action::ToggleMuteAuthor),
+        ("toggle_show_muted", Action::ToggleShowMuted),
+        ("toggle_focus_mode", Action::ToggleFocusMode),
+        ("cycle_notification_filter", Action::CycleNotificationFilter),
+        ("show_profile", Action::ShowProfile),
+        ("filter", Action::Filter),
+        ("next_match", Action::NextMatch),
+        ("prev_match", Action::PrevMatch),
+        ("next_link", Action::NextLink),
+        ("prev_link", Action::PrevLink),
+        ("activate_link", Action::ActivateLink),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|(action_name, _)| *action_name == name).map(|(_, action)| *action)
+    }
+
+    pub fn to_event_result(self) -> EventResult {
+        match self {
+            Action::Quit => EventResult::Quit,
+            Action::NextPost => EventResult::NextPost,
+            Action::PrevPost => EventResult::PrevPost,
+            Action::ScrollDown => EventResult::ScrollDown,
+            Action::ScrollUp => EventResult::ScrollUp,
+            Action::GoToFirst => EventResult::GoToFirst,
+            Action::GoToLast => EventResult::GoToLast,
+            Action::ToggleView => EventResult::ToggleView,
+            Action::Reply => EventResult::StartReply,
+            Action::NewPost => EventResult::StartNewPost,
+            Action::Refresh => EventResult::Refresh,
+            Action::ToggleHelp => EventResult::ToggleHelp,
+            Action::CountPollVotes => EventResult::CountPollVotes,
+            Action::ToggleBookmark => EventResult::ToggleBookmark,
+            Action::MarkAllRead => EventResult::MarkAllRead,
+            Action::CopyContent => EventResult::CopyContent,
+            Action::CopyPostUrl => EventResult::CopyPostUrl,
+            Action::ToggleRawView => EventResult::ToggleRawView,
+            Action::ToggleCollapseThread => EventResult::ToggleCollapseThread,
+            Action::ToggleAllBlocks => EventResult::ToggleAllBlocks,
+            Action::ToggleReactions => EventResult::ToggleReactions,
+            Action::ToggleMuteAuthor => EventResult::ToggleMuteAuthor,
+            Action::ToggleShowMuted => EventResult::ToggleShowMuted,
+            Action::ToggleFocusMode => EventResult::ToggleFocusMode,
+            Action::CycleNotificationFilter => EventResult::CycleNotificationFilter,
+            Action::ShowProfile => EventResult::ShowProfile,
+            Action::Filter => EventResult::StartFilter,
+            Action::NextMatch => EventResult::NextMatch,
+            Action::PrevMatch => EventResult::PrevMatch,
+            Action::NextLink => EventResult::NextLink,
+            Action::PrevLink => EventResult::PrevLink,
+            Action::ActivateLink => EventResult::ActivateLink,
+        }
+    }
+}
+
+/// Key codes with modifiers, normalized so they can be compared with incoming key events
+type Key = (KeyCode, KeyModifiers);
+
+/// Keybindings configured by the user
+#[derive(Debug, Clone, Default)]
+pub struct KeyBindings {
+    bindings: HashMap<Key, Action>,
+    /// Key string each bound action was configured with
+    key_names: HashMap<Action, String>,
+}
+
+impl KeyBindings {
+    /// Build the keybindings from the config table, returning warnings about invalid entries and conflicts
+    pub fn from_config(config: &HashMap<String, String>) -> (Self, Vec<String>) {
+        let mut bindings: HashMap<Key, (Action, &str)> = HashMap::new();
+        let mut warnings = vec![];
+        let mut key_names = HashMap::new();
+
+        // Sort so conflicts are reported the same way every time
+        let mut entries: Vec<_> = config.iter().collect();
+        entries.sort();
+
+        for (name, key_string) in entries {
+            let Some(action) = Action::from_name(name) else {
+                warnings.push(format!("Unknown keybinding action '{name}'"));
+                continue;
+            };
+            let Some(key) = parse_key(key_string) else {
+                warnings.push(format!("Invalid key '{key_string}' for keybinding '{name}'"));
+                continue;
+            };
+            if let Some((_, other_name)) = bindings.get(&key) {
+                warnings.push(format!("Key '{key_string}' is bound to both '{other_name}' and '{name}', using '{other_name}'"));
+                continue;
+            }
+            bindings.insert(key, (action, name));
+            key_names.insert(action, key_string.trim().to_string());
+        }
+
+        let bindings = bindings.into_iter().map(|(key, (action, _))| (key, action)).collect();
+        (Self { bindings, key_names }, warnings)
+    }
+
+    /// Key the user bound the action to, None when it keeps its default keys
+    pub fn key_name(&self, action: Action) -> Option<&str> {
+        self.key_names.get(&action).map(String::as_str)
+    }
+
+    /// Action bound to the key event, if any
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings.get(&normalize(key.code, key.modifiers)).copied()
+    }
+}
+
+/// Characters already encode shift, so it is ignored for them
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> Key {
+    let modifiers = modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+    match code {
+        KeyCode::Char(_) => (code, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+/// Parse a key string like `"j"`, `"C-n"`, `"Ctrl+n"`, `"M-v"`, `"Alt+v"`, `"S-Tab"` or `"PageDown"`
+fn parse_key(key_string: &str) -> Option<Key> {
+    const MODIFIER_PREFIXES: [(&str, KeyModifiers); 7] = [
+        ("C-", KeyModifiers::CONTROL),
+        ("ctrl+", KeyModifiers::CONTROL),
+        ("M-", KeyModifiers::ALT),
+        ("A-", KeyModifiers::ALT),
+        ("alt+", KeyModifiers::ALT),
+        ("S-", KeyModifiers::SHIFT),
+        ("shift+", KeyModifiers::SHIFT),
+    ];
+
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = key_string.trim();
+
+    // Strip modifier prefixes, the remainder must still name a key
+    while let Some((stripped, modifier)) = MODIFIER_PREFIXES.iter().find_map(|(prefix, modifier)| {
+        let stripped = rest.get(prefix.len()..)?;
+        let matches = rest[..prefix.len()].eq_ignore_ascii_case(prefix) && !stripped.is_empty();
+        matches.then_some((stripped, *modifier))
+    }) {
+        modifiers |= modifier;
+        rest = stripped;
+    }
+
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "enter" | "return" | "ret" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "esc" | "escape" => KeyCode::Esc,
+            "backspace" => KeyCode::Backspace,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            other => KeyCode::F(other.strip_prefix('f')?.parse().ok()?),
+        },
+    };
+
+    Some(normalize(code, modifiers))
+}
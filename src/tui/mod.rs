@@ -3,9 +3,13 @@
 //! This module provides a terminal-based user interface using ratatui,
 //! allowing users to scroll through posts, and in the future do some actions on them.
 
-use std::io;
+use std::io::{self, Write};
+use std::panic::{self, PanicHookInfo};
+use std::sync::Arc;
+use std::time::Duration;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
+    cursor::Show,
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -16,44 +20,91 @@ use ratatui::{
 
 pub mod activatable;
 pub mod app;
+pub mod clipboard;
+pub mod completion;
+pub mod desktop_notifications;
+pub mod drafts;
 pub mod events;
+pub mod external_editor;
+pub mod highlight;
+pub mod keybindings;
 pub mod modes;
 pub mod navigation;
+pub mod post_sets;
+pub mod shortcodes;
+pub mod theme;
 pub mod ui;
+pub mod undo;
+pub mod validation;
+pub mod words;
 
 pub use app::TUI;
+use crate::config::Config;
+use crate::filters::PostFilters;
 use crate::parser;
+use crate::remote::FetchOptions;
 
 /// Launch the TUI application
 pub async fn run_tui(
-    file_path: &std::path::Path,
+    config: &Config,
     user_profile: &parser::Profile,
     user_posts: Vec<parser::Post>,
     user_only: bool,
-    source_filter: Option<String>,
-    days_filter: Option<u32>,
+    filters: PostFilters,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Setup terminal
+    // Setup terminal, it and the panic hook are restored when the guards are dropped, however the TUI is left
+    let _panic_hook_guard = install_panic_hook();
     enable_raw_mode()?;
+    let terminal_guard = TerminalGuard;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app
-    let mut app = TUI::new(file_path, user_profile, user_posts, user_only, source_filter, days_filter).await?;
+    // Create app, showing the progress of fetching remote feeds meanwhile
+    let follow_count = if user_only {
+        0
+    } else {
+        user_profile.follow().as_ref().map_or(0, Vec::len)
+    };
+    let mut fetch_options = FetchOptions::from_config(config);
+    let mut app = if follow_count == 0 {
+        TUI::new(config, user_profile, user_posts, user_only, filters, &fetch_options).await?
+    } else {
+        let (progress_sender, mut progress_receiver) = tokio::sync::mpsc::unbounded_channel();
+        fetch_options.progress = Some(progress_sender);
+        let new_app = TUI::new(config, user_profile, user_posts, user_only, filters, &fetch_options);
+        tokio::pin!(new_app);
 
-    // Run the event loop
+        let mut fetched = 0;
+        let mut input_check = tokio::time::interval(Duration::from_millis(50));
+        terminal.draw(|f| ui::loading::draw_fetch_progress(f, fetched, follow_count))?;
+        loop {
+            tokio::select! {
+                app = &mut new_app => break app?,
+                Some(()) = progress_receiver.recv() => {
+                    fetched += 1;
+                    terminal.draw(|f| ui::loading::draw_fetch_progress(f, fetched, follow_count))?;
+                }
+                // The fetch is abandoned when the user quits while waiting for it
+                _ = input_check.tick() => match loading_input()? {
+                    LoadingInput::Quit => return Ok(()),
+                    LoadingInput::Resize => {
+                        terminal.autoresize()?;
+                        terminal.draw(|f| ui::loading::draw_fetch_progress(f, fetched, follow_count))?;
+                    }
+                    LoadingInput::None => {}
+                },
+            }
+        }
+    };
+
+    // Run the event loop, keeping the last changes of a post being written and the posts read
     let result = run_app(&mut terminal, &mut app).await;
+    app.save_draft();
+    app.save_read_posts();
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    drop(terminal_guard);
 
     if let Err(err) = result {
         println!("{err:?}");
@@ -62,6 +113,91 @@ pub async fn run_tui(
     Ok(())
 }
 
+/// Restores the terminal when dropped, so it is left usable on errors as well
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show);
+    }
+}
+
+/// What the user did while the feeds were loading
+enum LoadingInput {
+    None,
+    Quit,
+    Resize,
+}
+
+/// Read the pending input while the feeds are loading, `q` and Ctrl-C quit
+fn loading_input() -> io::Result<LoadingInput> {
+    let mut input = LoadingInput::None;
+    while event::poll(Duration::ZERO)? {
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if ctrl_c || key.code == KeyCode::Char('q') {
+                    return Ok(LoadingInput::Quit);
+                }
+            }
+            Event::Resize(_, _) => input = LoadingInput::Resize,
+            _ => {}
+        }
+    }
+    Ok(input)
+}
+
+/// Panic hook that was installed before the TUI's own
+type PanicHook = Arc<dyn Fn(&PanicHookInfo) + Sync + Send>;
+
+/// Restore the terminal before a panic is reported, otherwise the message is garbled by raw mode,
+/// hidden by the alternate screen, and the shell is left unusable
+fn install_panic_hook() -> PanicHookGuard {
+    let previous_hook: PanicHook = Arc::from(panic::take_hook());
+    let hook = previous_hook.clone();
+    panic::set_hook(Box::new(move |info| {
+        // The terminal may be in any state, restore as much of it as possible
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show);
+        hook(info);
+    }));
+    PanicHookGuard(previous_hook)
+}
+
+/// Puts back the panic hook replaced by `install_panic_hook` when dropped
+struct PanicHookGuard(PanicHook);
+
+impl Drop for PanicHookGuard {
+    fn drop(&mut self) {
+        let previous_hook = self.0.clone();
+        let _ = panic::take_hook();
+        panic::set_hook(Box::new(move |info| previous_hook(info)));
+    }
+}
+
+/// Hand the terminal over to `$EDITOR` to edit the post content, then restore the TUI
+fn run_external_editor<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut TUI,
+) -> io::Result<()> {
+    let (Some(editor), Some(content)) = (external_editor::editor_command(), app.editor_content()) else {
+        return Ok(());
+    };
+
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+
+    let result = external_editor::edit_text(&editor, content);
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+
+    app.apply_external_edit(result);
+    Ok(())
+}
+
 /// Main event loop for the TUI
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
@@ -70,39 +206,54 @@ async fn run_app<B: ratatui::backend::Backend>(
     loop {
         // Update cursor blink state
         app.update_cursor_blink();
+        app.save_if_idle();
         
-        terminal.draw(|f| {
-            ui::draw_ui(
-                f,
-                &app.mode,
-                &app.view_mode,
-                &app.posts,
-                &app.notification_feed,
-                &app.thread_view,
-                &app.navigator,
-                app.current_post(),
-                &app.reply_state,
-                &app.new_post_state,
-                &app.poll_vote_state,
-                &app.status_message,
-                app.cursor_visible,
-                app.help_scroll,
-                &app.activatable_collector,
-                Some(&app.activatable_manager),
-            )
-        })?;
+        terminal.draw(|f| ui::draw_ui(f, app))?;
+
+        // Refresh after drawing, so the "Refreshing..." status is visible while it runs
+        if app.refresh_requested {
+            app.refresh_feed().await;
+            // Written past ratatui, the bell only makes a sound and leaves the screen alone
+            if app.bell_requested {
+                app.bell_requested = false;
+                let mut stdout = io::stdout();
+                stdout.write_all(b"\x07")?;
+                stdout.flush()?;
+            }
+            continue;
+        }
+
+        if app.author_feed_request.is_some() {
+            app.fetch_author_feed().await;
+            continue;
+        }
+
+        if app.external_editor_requested {
+            app.external_editor_requested = false;
+            run_external_editor(terminal, app)?;
+            continue;
+        }
 
         // Use poll to check for events with timeout for cursor blinking
         if crossterm::event::poll(std::time::Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    crossterm::event::KeyCode::Char('q') if app.mode == modes::AppMode::Browsing => {
+            match event::read()? {
+                Event::Key(key) => {
+                    app.handle_event(key);
+                    if app.quit_requested {
                         return Ok(());
                     }
-                    _ => {
-                        app.handle_event(key);
-                    }
                 }
+                Event::Mouse(mouse) => {
+                    app.handle_mouse_event(mouse);
+                }
+                // Overlays are laid out from the terminal size, redraw them right away, measuring the
+                // new scroll limits, and clamp the scroll offsets before the next draw shows them
+                Event::Resize(_, _) => {
+                    terminal.autoresize()?;
+                    terminal.draw(|f| ui::draw_ui(f, app))?;
+                    app.clamp_scroll_offsets();
+                }
+                _ => {}
             }
         }
     }
@@ -1,5 +1,8 @@
 //! Application mode definitions and state management.
 
+use org_social_lib_rs::notifications::NotificationType;
+use org_social_lib_rs::threading::ThreadView;
+
 #[derive(Clone, PartialEq)]
 pub enum AppMode {
     Browsing,
@@ -7,6 +10,14 @@ pub enum AppMode {
     NewPost,
     Help,
     PollVote,
+    /// Typing a post ID to jump to
+    Command,
+    /// Profile of the current post's author shown over the feed
+    Profile,
+    /// Typing a query narrowing down the shown posts
+    Filter,
+    /// Asking what to do with the post or reply being written before leaving it
+    ConfirmDiscard,
 }
 
 #[derive(Clone, PartialEq)]
@@ -14,6 +25,10 @@ pub enum ViewMode {
     List,
     Threaded,
     Notifications,
+    /// Bookmarked posts only
+    Bookmarks,
+    /// Posts written by the user only
+    OwnPosts,
 }
 
 impl ViewMode {
@@ -21,7 +36,21 @@ impl ViewMode {
         match self {
             ViewMode::List => ViewMode::Threaded,
             ViewMode::Threaded => ViewMode::Notifications,
-            ViewMode::Notifications => ViewMode::List,
+            ViewMode::Notifications => ViewMode::Bookmarks,
+            ViewMode::Bookmarks => ViewMode::OwnPosts,
+            ViewMode::OwnPosts => ViewMode::List,
+        }
+    }
+
+    /// Parse a view name as used in the config and the `--view` flag
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "list" => Some(ViewMode::List),
+            "threaded" => Some(ViewMode::Threaded),
+            "notifications" => Some(ViewMode::Notifications),
+            "bookmarks" => Some(ViewMode::Bookmarks),
+            "own" => Some(ViewMode::OwnPosts),
+            _ => None,
         }
     }
 
@@ -30,6 +59,113 @@ impl ViewMode {
             ViewMode::List => "List View",
             ViewMode::Threaded => "Threaded View",
             ViewMode::Notifications => "Notifications",
+            ViewMode::Bookmarks => "Bookmarks",
+            ViewMode::OwnPosts => "Own Posts",
+        }
+    }
+}
+
+/// Order of the threads in the threaded view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThreadSort {
+    /// Most recently active thread first, by the latest post anywhere in the thread
+    Activity,
+    /// Order of the first posts, as the threads are built
+    RootTime,
+}
+
+impl ThreadSort {
+    /// Parse a sort name as used in the config
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "activity" => Some(ThreadSort::Activity),
+            "root_time" => Some(ThreadSort::RootTime),
+            _ => None,
+        }
+    }
+
+    /// Reorder the threads, threads without any post time go last
+    pub fn apply(self, thread_view: &mut ThreadView) {
+        if self == ThreadSort::Activity {
+            thread_view
+                .roots
+                .sort_by_cached_key(|root| std::cmp::Reverse(root.flatten().iter().filter_map(|post| post.time()).max()));
+        }
+    }
+}
+
+/// Kinds of notifications shown in the notifications view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationFilter {
+    All,
+    /// Posts mentioning the user, replies included when they also mention them
+    Mentions,
+    /// Replies to the user's posts, mentions included when they also reply
+    Replies,
+}
+
+impl NotificationFilter {
+    pub fn next(self) -> Self {
+        match self {
+            NotificationFilter::All => NotificationFilter::Mentions,
+            NotificationFilter::Mentions => NotificationFilter::Replies,
+            NotificationFilter::Replies => NotificationFilter::All,
         }
     }
+
+    pub fn matches(self, notification_type: &NotificationType) -> bool {
+        match self {
+            NotificationFilter::All => true,
+            NotificationFilter::Mentions => !matches!(notification_type, NotificationType::Reply),
+            NotificationFilter::Replies => !matches!(notification_type, NotificationType::Mention),
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            NotificationFilter::All => "All",
+            NotificationFilter::Mentions => "Mentions",
+            NotificationFilter::Replies => "Replies",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{full_id, org_post, parse_posts, SOURCE};
+
+    const OLD_ROOT: &str = "2025-01-01T09:00:00+0000";
+    const BUSY_ROOT: &str = "2025-01-01T10:00:00+0000";
+    const NEW_ROOT: &str = "2025-01-01T11:00:00+0000";
+
+    /// Threads whose replies come in a different order than their roots
+    fn thread_view() -> ThreadView {
+        ThreadView::from_posts(parse_posts(SOURCE, &[
+            org_post(OLD_ROOT, None, "Old root"),
+            org_post(BUSY_ROOT, None, "Busy root"),
+            org_post(NEW_ROOT, None, "New root"),
+            org_post("no-time", None, "Root without a time"),
+            org_post("2025-01-01T12:00:00+0000", Some(&full_id(NEW_ROOT)), "Early reply to the new root"),
+            org_post("2025-01-01T13:00:00+0000", Some(&full_id(BUSY_ROOT)), "Late reply to the busy root"),
+        ]))
+    }
+
+    fn root_ids(thread_view: &ThreadView) -> Vec<&str> {
+        thread_view.roots.iter().map(|root| root.post.id()).collect()
+    }
+
+    #[test]
+    fn activity_sorts_threads_by_their_latest_post() {
+        let mut thread_view = thread_view();
+        ThreadSort::Activity.apply(&mut thread_view);
+        assert_eq!(root_ids(&thread_view), vec![BUSY_ROOT, NEW_ROOT, OLD_ROOT, "no-time"]);
+    }
+
+    #[test]
+    fn root_time_keeps_threads_by_their_first_post() {
+        let mut thread_view = thread_view();
+        ThreadSort::RootTime.apply(&mut thread_view);
+        assert_eq!(root_ids(&thread_view), vec![NEW_ROOT, BUSY_ROOT, OLD_ROOT, "no-time"]);
+    }
 }
@@ -1,8 +1,19 @@
 //! Navigation logic for posts and threads.
 
 use super::modes::ViewMode;
-use org_social_lib_rs::{notifications, parser, threading};
+use org_social_lib_rs::threading;
+use std::collections::HashSet;
 
+/// Number of posts of a thread shown in the threaded view, only the root if it is collapsed
+pub fn visible_thread_len(thread: &threading::ThreadNode, collapsed_threads: &HashSet<String>) -> usize {
+    if collapsed_threads.contains(&thread.post.full_id()) {
+        1
+    } else {
+        thread.flatten().len()
+    }
+}
+
+#[derive(Clone)]
 pub struct Navigator {
     pub selected_post: usize,
     pub selected_thread: usize,
@@ -20,60 +31,42 @@ impl Navigator {
         }
     }
 
-    pub fn next_post(&mut self, view_mode: &ViewMode, posts: &[parser::Post], thread_view: &threading::ThreadView, notification_feed: Option<&notifications::NotificationFeed>) {
+    pub fn next_post(&mut self, view_mode: &ViewMode, list_len: usize, thread_view: &threading::ThreadView, collapsed_threads: &HashSet<String>) {
         match view_mode {
-            ViewMode::List => {
-                if !posts.is_empty() && self.selected_post < posts.len().saturating_sub(1) {
+            ViewMode::List | ViewMode::Bookmarks | ViewMode::OwnPosts | ViewMode::Notifications => {
+                if self.selected_post + 1 < list_len {
                     self.selected_post += 1;
                     self.scroll_offset = 0;
                 }
             }
             ViewMode::Threaded => {
-                self.next_threaded_post(thread_view);
-            }
-            ViewMode::Notifications => {
-                if let Some(notification_feed) = notification_feed {
-                    if !notification_feed.notifications.is_empty() && 
-                       self.selected_post < notification_feed.notifications.len().saturating_sub(1) {
-                        self.selected_post += 1;
-                        self.scroll_offset = 0;
-                    }
-                }
+                self.next_threaded_post(thread_view, collapsed_threads);
             }
         }
     }
 
-    pub fn prev_post(&mut self, view_mode: &ViewMode, posts: &[parser::Post], thread_view: &threading::ThreadView, notification_feed: Option<&notifications::NotificationFeed>) {
+    pub fn prev_post(&mut self, view_mode: &ViewMode, list_len: usize, thread_view: &threading::ThreadView, collapsed_threads: &HashSet<String>) {
         match view_mode {
-            ViewMode::List => {
-                if !posts.is_empty() && self.selected_post > 0 {
+            ViewMode::List | ViewMode::Bookmarks | ViewMode::OwnPosts | ViewMode::Notifications => {
+                if list_len > 0 && self.selected_post > 0 {
                     self.selected_post -= 1;
                     self.scroll_offset = 0;
                 }
             }
             ViewMode::Threaded => {
-                self.prev_threaded_post(thread_view);
-            }
-            ViewMode::Notifications => {
-                if let Some(notification_feed) = notification_feed {
-                    if !notification_feed.notifications.is_empty() && self.selected_post > 0 {
-                        self.selected_post -= 1;
-                        self.scroll_offset = 0;
-                    }
-                }
+                self.prev_threaded_post(thread_view, collapsed_threads);
             }
         }
     }
 
-    fn next_threaded_post(&mut self, thread_view: &threading::ThreadView) {
+    fn next_threaded_post(&mut self, thread_view: &threading::ThreadView, collapsed_threads: &HashSet<String>) {
         if thread_view.is_empty() {
             return;
         }
 
+        // Replies of collapsed threads are hidden, so they are skipped
         let current_thread = &thread_view.roots[self.selected_thread];
-        let thread_posts = current_thread.flatten();
-        
-        if self.selected_thread_post < thread_posts.len().saturating_sub(1) {
+        if self.selected_thread_post < visible_thread_len(current_thread, collapsed_threads).saturating_sub(1) {
             // Move to next post in current thread
             self.selected_thread_post += 1;
         } else if self.selected_thread < thread_view.roots.len().saturating_sub(1) {
@@ -84,7 +77,7 @@ impl Navigator {
         self.scroll_offset = 0;
     }
 
-    fn prev_threaded_post(&mut self, thread_view: &threading::ThreadView) {
+    fn prev_threaded_post(&mut self, thread_view: &threading::ThreadView, collapsed_threads: &HashSet<String>) {
         if thread_view.is_empty() {
             return;
         }
@@ -96,32 +89,37 @@ impl Navigator {
             // Move to previous thread, select last post
             self.selected_thread -= 1;
             let current_thread = &thread_view.roots[self.selected_thread];
-            let thread_posts = current_thread.flatten();
-            self.selected_thread_post = thread_posts.len().saturating_sub(1);
+            self.selected_thread_post = visible_thread_len(current_thread, collapsed_threads).saturating_sub(1);
         }
         self.scroll_offset = 0;
     }
 
-    pub fn go_to_first(&mut self, posts: &[parser::Post]) {
-        if !posts.is_empty() {
+    pub fn go_to_first(&mut self, list_len: usize) {
+        if list_len > 0 {
             self.selected_post = 0;
             self.scroll_offset = 0;
         }
     }
 
-    pub fn go_to_last(&mut self, posts: &[parser::Post]) {
-        if !posts.is_empty() {
-            self.selected_post = posts.len() - 1;
+    pub fn go_to_last(&mut self, list_len: usize) {
+        if list_len > 0 {
+            self.selected_post = list_len - 1;
             self.scroll_offset = 0;
         }
     }
 
-    pub fn scroll_down(&mut self, posts: &[parser::Post]) {
-        if let Some(post) = posts.get(self.selected_post) {
-            let content_lines = post.content().lines().count();
-            if self.scroll_offset < content_lines.saturating_sub(1) {
-                self.scroll_offset += 1;
-            }
+    /// Scroll the content down by one row, up to the last rendered row of the post
+    pub fn scroll_down(&mut self, max_scroll: usize) {
+        self.scroll_offset = (self.scroll_offset + 1).min(max_scroll);
+    }
+
+    /// Scroll just enough to show the row in a pane of the given height
+    pub fn scroll_to_row(&mut self, row: usize, height: usize, max_scroll: usize) {
+        let height = height.max(1);
+        if row < self.scroll_offset {
+            self.scroll_offset = row;
+        } else if row >= self.scroll_offset + height {
+            self.scroll_offset = (row + 1 - height).min(max_scroll);
         }
     }
 
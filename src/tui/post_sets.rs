@@ -0,0 +1,43 @@
+//! Sets of posts persisted across restarts, like bookmarks and read posts.
+//! Muted authors are stored the same way, see `crate::mutes`.
+//!
+//! Each set is stored as the full IDs (`url#id`) of its posts, one per line,
+//! in a text file in the config directory.
+
+use crate::config::Config;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// File of the bookmarked posts
+pub const BOOKMARKS_FILE: &str = "bookmarks.txt";
+/// File of the posts that were already viewed
+pub const READ_POSTS_FILE: &str = "read_posts.txt";
+
+fn post_set_path(file_name: &str) -> Option<PathBuf> {
+    Some(Config::get_config_dir().ok()?.join(file_name))
+}
+
+/// Load the post IDs from the file, a missing file means an empty set
+pub fn load_post_set(file_name: &str) -> HashSet<String> {
+    post_set_path(file_name)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Save the post IDs to the file, sorted so the file stays stable
+pub fn save_post_set(file_name: &str, ids: &HashSet<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = post_set_path(file_name).ok_or("Unable to determine config directory")?;
+    let mut ids: Vec<&String> = ids.iter().collect();
+    ids.sort();
+    let contents: String = ids.iter().map(|id| format!("{id}\n")).collect();
+    std::fs::write(path, contents)?;
+    Ok(())
+}
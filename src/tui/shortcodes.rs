@@ -0,0 +1,74 @@
+//! Expansion of `:name:` emoji shortcodes typed in the mood field.
+
+/// Common shortcodes and the emoji they stand for
+const SHORTCODES: &[(&str, &str)] = &[
+    ("smile", "😄"),
+    ("grin", "😁"),
+    ("joy", "😂"),
+    ("laughing", "😆"),
+    ("wink", "😉"),
+    ("blush", "😊"),
+    ("heart_eyes", "😍"),
+    ("thinking", "🤔"),
+    ("neutral_face", "😐"),
+    ("sweat_smile", "😅"),
+    ("sunglasses", "😎"),
+    ("cry", "😢"),
+    ("sob", "😭"),
+    ("angry", "😠"),
+    ("rage", "😡"),
+    ("scream", "😱"),
+    ("sleeping", "😴"),
+    ("confused", "😕"),
+    ("upside_down", "🙃"),
+    ("heart", "❤"),
+    ("broken_heart", "💔"),
+    ("+1", "👍"),
+    ("thumbsup", "👍"),
+    ("-1", "👎"),
+    ("thumbsdown", "👎"),
+    ("clap", "👏"),
+    ("pray", "🙏"),
+    ("wave", "👋"),
+    ("ok_hand", "👌"),
+    ("muscle", "💪"),
+    ("eyes", "👀"),
+    ("fire", "🔥"),
+    ("star", "⭐"),
+    ("sparkles", "✨"),
+    ("tada", "🎉"),
+    ("rocket", "🚀"),
+    ("100", "💯"),
+    ("coffee", "☕"),
+    ("check", "✅"),
+    ("x", "❌"),
+    ("warning", "⚠"),
+    ("bulb", "💡"),
+];
+
+/// Replace known `:name:` shortcodes with their emoji, unknown ones are kept as typed
+pub fn expand_shortcodes(text: &str) -> String {
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(':') {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let emoji = after.find(':').and_then(|end| {
+            let name = &after[..end];
+            SHORTCODES.iter().find(|(code, _)| *code == name).map(|(_, emoji)| (*emoji, end))
+        });
+        match emoji {
+            Some((emoji, end)) => {
+                expanded.push_str(emoji);
+                rest = &after[end + 1..];
+            }
+            None => {
+                // Keep the colon, it may still open a later shortcode
+                expanded.push(':');
+                rest = after;
+            }
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
@@ -0,0 +1,78 @@
+//! TUI color theme.
+
+use crate::config::ThemeConfig;
+use ratatui::style::Color;
+
+/// Colors authors are picked from with `author_colors`, readable on dark and light terminals
+const AUTHOR_PALETTE: [Color; 8] = [
+    Color::Green,
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::LightBlue,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightMagenta,
+];
+
+/// Colors used across the TUI, parsed from the `[theme]` config section
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub author: Color,
+    pub timestamp: Color,
+    pub link: Color,
+    pub mention: Color,
+    pub tag: Color,
+    pub selected: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            author: Color::Green,
+            timestamp: Color::Blue,
+            link: Color::Blue,
+            mention: Color::Cyan,
+            tag: Color::Cyan,
+            selected: Color::DarkGray,
+        }
+    }
+}
+
+impl Theme {
+    /// Parse the configured colors, returning warnings for invalid ones which keep their default
+    pub fn from_config(config: &ThemeConfig) -> (Self, Vec<String>) {
+        let mut theme = Self::default();
+        let mut warnings = vec![];
+
+        let roles = [
+            ("author", &config.author, &mut theme.author),
+            ("timestamp", &config.timestamp, &mut theme.timestamp),
+            ("link", &config.link, &mut theme.link),
+            ("mention", &config.mention, &mut theme.mention),
+            ("tag", &config.tag, &mut theme.tag),
+            ("selected", &config.selected, &mut theme.selected),
+        ];
+        for (role, value, color) in roles {
+            match value.parse() {
+                Ok(parsed) => *color = parsed,
+                Err(_) => warnings.push(format!("Invalid {role} color '{value}'")),
+            }
+        }
+
+        (theme, warnings)
+    }
+
+    /// Color of an author's name: picked from the palette by the name when `per_author` is set,
+    /// the theme's author color otherwise
+    pub fn author_color(&self, author: &str, per_author: bool) -> Color {
+        if !per_author {
+            return self.author;
+        }
+        // FNV-1a, so authors keep their color across runs and Rust versions
+        let hash = author.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+        AUTHOR_PALETTE[(hash % AUTHOR_PALETTE.len() as u64) as usize]
+    }
+}
@@ -0,0 +1,51 @@
+//! Popup listing the followed nicks matching the `@nick` being typed.
+
+use super::super::completion::NickCompletion;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// Widest the popup gets, in columns
+const POPUP_WIDTH: u16 = 40;
+
+/// Draw the completions in the bottom left corner of the content field
+pub fn draw_nick_completion(f: &mut Frame, field: Rect, completion: &NickCompletion) {
+    let height = completion.matches.len() as u16 + 2;
+    if field.height < height + 2 || field.width < 12 {
+        return;
+    }
+    let popup = Rect {
+        x: field.x + 1,
+        y: field.y + field.height - height - 1,
+        width: POPUP_WIDTH.min(field.width - 2),
+        height,
+    };
+
+    let items: Vec<ListItem> = completion
+        .matches
+        .iter()
+        .map(|(nick, url)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("@{nick}"), Style::default().fg(Color::Cyan)),
+                Span::styled(format!(" {url}"), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Tab/Enter:insert | Esc:close")
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .style(Style::default().bg(Color::Black))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(completion.selected));
+
+    f.render_widget(Clear, popup);
+    f.render_stateful_widget(list, popup, &mut state);
+}
@@ -0,0 +1,39 @@
+//! Confirmation shown before leaving a post or reply that has been written.
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+fn key(text: &str) -> Span<'static> {
+    Span::styled(text.to_string(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+}
+
+/// Draw the discard confirmation centered over the editor
+pub fn draw_confirm_discard(f: &mut Frame, area: Rect) {
+    let lines = vec![
+        Line::from("You have unsent text, what should happen to it?"),
+        Line::from(""),
+        Line::from(vec![
+            key("d"),
+            Span::raw(" Discard  "),
+            key("k"),
+            Span::raw(" Keep editing  "),
+            key("s"),
+            Span::raw(" Save draft"),
+        ]),
+    ];
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Leave the editor?")
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let [_, column, _] = Layout::horizontal([Constraint::Fill(1), Constraint::Length(52), Constraint::Fill(1)]).areas(area);
+    let [_, popup, _] = Layout::vertical([Constraint::Fill(1), Constraint::Length(5), Constraint::Fill(1)]).areas(column);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block).style(Style::default().bg(Color::Black)), popup);
+}
@@ -1,14 +1,21 @@
 //! Post content display UI component.
 
+use crate::formatting;
+use crate::search::PostMatcher;
 use crate::tui::activatable::{self, ActivatableCollector, ActivatableManager};
+use crate::tui::app::TUI;
+use crate::tui::highlight;
+use crate::tui::theme::Theme;
 use org_social_lib_rs::parser;
 use org_social_lib_rs::tokenizer::Token;
 use org_social_lib_rs::blocks::ActivatableElement;
+use std::collections::HashSet;
 use ratatui::{
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
     Frame,
 };
 
@@ -17,8 +24,10 @@ fn process_post_tokens(
     post: &parser::Post,
     collector: &ActivatableCollector,
     activatable_manager: Option<&ActivatableManager>,
-    scroll_offset: usize,
-) -> Vec<Line<'static>> {
+    theme: &Theme,
+    code_line_numbers: bool,
+    matcher: Option<&PostMatcher>,
+) -> (Vec<Line<'static>>, Vec<Option<usize>>) {
     let mut lines: Vec<Vec<Span<'static>>> = vec![];
     let mut current_line: Vec<Span<'static>> = vec![];
     let mut current_line_num = 0;
@@ -27,13 +36,18 @@ fn process_post_tokens(
     // Process each token from the post
     for token in post.tokens() {
         let token_spans = token_to_spans(
-            token.clone(),
+            strip_carriage_returns(token.clone()),
             collector,
             activatable_manager,
+            theme,
+            matcher,
             current_line_num,
             &mut current_col,
         );
 
+        // Columns were counted by token_to_spans for the whole token, once it is split across lines
+        // the columns of the new line are counted here instead
+        let mut after_newline = false;
         for span in token_spans {
             // Check if this span contains newlines
             let text = &span.content;
@@ -45,8 +59,12 @@ fn process_post_tokens(
                         // First part goes to current line
                         if !line_text.is_empty() {
                             current_line.push(Span::styled(line_text.to_string(), span.style));
+                            if after_newline {
+                                current_col += line_text.chars().count();
+                            }
                         }
                     } else {
+                        after_newline = true;
                         // Complete current line and start a new one
                         lines.push(current_line);
                         current_line = vec![];
@@ -55,13 +73,15 @@ fn process_post_tokens(
                         
                         if !line_text.is_empty() {
                             current_line.push(Span::styled(line_text.to_string(), span.style));
-                            current_col += line_text.len();
+                            current_col += line_text.chars().count();
                         }
                     }
                 }
             } else {
                 // No newlines, just add to current line
-                current_col += text.len();
+                if after_newline {
+                    current_col += text.chars().count();
+                }
                 current_line.push(span);
             }
         }
@@ -74,39 +94,176 @@ fn process_post_tokens(
 
     // Apply block styling and handle collapsed/expanded blocks
     let blocks = post.blocks();
-    let styled_lines = apply_block_styling(lines, blocks, post, activatable_manager, collector);
+    let (styled_lines, line_map) = apply_block_styling(lines, blocks, post, activatable_manager, collector, code_line_numbers);
 
-    // Apply scrolling
-    styled_lines
-        .into_iter()
-        .skip(scroll_offset)
-        .map(Line::from)
+    (styled_lines.into_iter().map(Line::from).collect(), line_map)
+}
+
+/// Row each line starts on after wrapping
+fn line_start_rows(lines: &[Line], width: u16, trim: bool) -> Vec<usize> {
+    let mut row_starts = Vec::with_capacity(lines.len());
+    let mut row = 0;
+    for line in lines {
+        row_starts.push(row);
+        row += Paragraph::new(line.clone()).wrap(Wrap { trim }).line_count(width);
+    }
+    row_starts
+}
+
+/// Rows of the lines with a match of the filter, so `n` and `N` can scroll to them
+fn match_rows(lines: &[Line], width: u16, trim: bool, matcher: Option<&PostMatcher>) -> Vec<usize> {
+    let Some(matcher) = matcher else {
+        return Vec::new();
+    };
+    let row_starts = line_start_rows(lines, width, trim);
+    lines
+        .iter()
+        .zip(row_starts)
+        .filter(|(line, _)| matcher.is_match(&line.spans.iter().map(|span| span.content.as_ref()).collect::<String>()))
+        .map(|(_, row)| row)
         .collect()
 }
 
+/// Move the collected elements from content lines to the rows they are rendered on after wrapping,
+/// so they match the scroll offset and mouse rows. Elements hidden in collapsed blocks are dropped.
+/// Elements on a wrapped line get the row and columns of their first char, running to the end of
+/// that row when they continue on the next one.
+fn place_collected_elements(collector: &ActivatableCollector, line_map: &[Option<usize>], lines: &[Line<'static>], width: u16) {
+    let row_starts = line_start_rows(lines, width, true);
+
+    if let Ok(mut elements) = collector.lock() {
+        elements.retain_mut(|(_, line, start_col, end_col, _)| {
+            let Some(index) = line_map.get(*line).copied().flatten() else {
+                return false;
+            };
+            let Some(&shown_row) = row_starts.get(index) else {
+                return false;
+            };
+            *line = shown_row;
+
+            let wraps = Paragraph::new(lines[index].clone()).wrap(Wrap { trim: true }).line_count(width) > 1;
+            if wraps {
+                let positions = wrapped_char_positions(&lines[index], width);
+                if let Some(&(row, col, _)) = positions.get(*start_col) {
+                    let last = positions.get(end_col.saturating_sub(1).max(*start_col)).copied();
+                    *line = shown_row + row;
+                    *start_col = col;
+                    *end_col = match last {
+                        Some((last_row, _, last_end)) if last_row == row => last_end,
+                        _ => width as usize,
+                    };
+                }
+            }
+            true
+        });
+    }
+}
+
+/// Row, first column and end column each char of the line is rendered at, relative to the line's
+/// first row. The line is wrapped by rendering it, so the rows match the content paragraph's.
+fn wrapped_char_positions(line: &Line, width: u16) -> Vec<(usize, usize, usize)> {
+    let paragraph = Paragraph::new(line.clone()).wrap(Wrap { trim: true });
+    let rows = paragraph.line_count(width).min(u16::MAX as usize) as u16;
+    let area = Rect::new(0, 0, width, rows);
+    let mut buffer = Buffer::empty(area);
+    paragraph.render(area, &mut buffer);
+    let symbol = |col: usize, row: usize| buffer.cell((col as u16, row as u16)).map(|cell| cell.symbol()).unwrap_or("");
+
+    let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+    let mut positions = Vec::with_capacity(text.chars().count());
+    let (mut row, mut col) = (0, 0);
+    for ch in text.chars() {
+        let ch_width = Span::raw(ch.to_string()).width();
+        // Combining chars are rendered in the cell of the char before them
+        if ch_width == 0 {
+            positions.push(positions.last().copied().unwrap_or((row, col, col)));
+            continue;
+        }
+        loop {
+            if row >= rows as usize {
+                positions.push((row.saturating_sub(1), col, col));
+                break;
+            }
+            if col + ch_width <= width as usize && symbol(col, row).starts_with(ch) {
+                positions.push((row, col, col + ch_width));
+                col += ch_width;
+                break;
+            }
+            // Whitespace the wrapping trimmed isn't rendered, other chars continue on the next row
+            if ch.is_whitespace() {
+                positions.push((row, col, col));
+                break;
+            }
+            row += 1;
+            col = 0;
+        }
+    }
+    positions
+}
+
+/// Drop the carriage returns of CRLF line endings, so they are neither rendered nor counted as columns
+fn strip_carriage_returns(token: Token) -> Token {
+    let strip = |text: String| if text.contains('\r') { text.replace('\r', "") } else { text };
+    match token {
+        Token::PlainText(text) => Token::PlainText(strip(text)),
+        Token::Bold(text) => Token::Bold(strip(text)),
+        Token::Italic(text) => Token::Italic(strip(text)),
+        Token::BoldItalic(text) => Token::BoldItalic(strip(text)),
+        Token::Strikethrough(text) => Token::Strikethrough(strip(text)),
+        Token::Underline(text) => Token::Underline(strip(text)),
+        Token::InlineCode(text) => Token::InlineCode(strip(text)),
+        Token::Link { url, description } => Token::Link { url, description: description.map(strip) },
+        Token::Mention { .. } => token,
+    }
+}
+
+/// Split plain text around the matches of the filter, which are shown in reverse video
+fn highlight_filter_matches(text: String, matcher: Option<&PostMatcher>) -> Vec<Span<'static>> {
+    let ranges = matcher.map(|matcher| matcher.find_ranges(&text)).unwrap_or_default();
+    if ranges.is_empty() {
+        return vec![Span::raw(text)];
+    }
+
+    let mut spans = Vec::new();
+    let mut last_end = 0;
+    for range in ranges {
+        if range.start > last_end {
+            spans.push(Span::raw(text[last_end..range.start].to_string()));
+        }
+        spans.push(Span::styled(text[range.clone()].to_string(), Style::default().add_modifier(Modifier::REVERSED)));
+        last_end = range.end;
+    }
+    if last_end < text.len() {
+        spans.push(Span::raw(text[last_end..].to_string()));
+    }
+    spans
+}
+
 /// Convert a single token to one or more styled spans
 fn token_to_spans(
     token: Token,
     collector: &ActivatableCollector,
     activatable_manager: Option<&ActivatableManager>,
+    theme: &Theme,
+    matcher: Option<&PostMatcher>,
     line_num: usize,
     col_offset: &mut usize,
 ) -> Vec<Span<'static>> {
     match token {
         Token::PlainText(text) => {
-            *col_offset += text.len();
-            vec![Span::raw(text)]
+            *col_offset += text.chars().count();
+            highlight_filter_matches(text, matcher)
         }
         Token::Bold(text) => {
-            *col_offset += text.len();
+            *col_offset += text.chars().count();
             vec![Span::styled(text, Style::default().add_modifier(Modifier::BOLD))]
         }
         Token::Italic(text) => {
-            *col_offset += text.len();
+            *col_offset += text.chars().count();
             vec![Span::styled(text, Style::default().add_modifier(Modifier::ITALIC))]
         }
         Token::BoldItalic(text) => {
-            *col_offset += text.len();
+            *col_offset += text.chars().count();
             vec![Span::styled(
                 text,
                 Style::default()
@@ -117,7 +274,7 @@ fn token_to_spans(
         Token::Link { url, description } => {
             let display_text = description.unwrap_or(url.clone());
             let start_col = *col_offset;
-            let end_col = start_col + display_text.len();
+            let end_col = start_col + display_text.chars().count();
 
             // Add link to collector
             activatable::collect_hyperlink(
@@ -129,13 +286,14 @@ fn token_to_spans(
                 end_col,
             );
 
-            *col_offset += display_text.len();
+            *col_offset += display_text.chars().count();
 
             // Create styled span for the hyperlink with focus checking
             vec![activatable::create_hyperlink_span(
                 display_text,
                 &url,
                 activatable_manager,
+                theme,
             )]
         }
         Token::Mention { url, username } => {
@@ -145,7 +303,7 @@ fn token_to_spans(
                 format!("@{username}")
             };
             let start_col = *col_offset;
-            let end_col = start_col + display_text.len();
+            let end_col = start_col + display_text.chars().count();
 
             // Add mention to collector
             activatable::collect_mention(
@@ -157,31 +315,32 @@ fn token_to_spans(
                 end_col,
             );
 
-            *col_offset += display_text.len();
+            *col_offset += display_text.chars().count();
 
             // Create styled span for the mention with focus checking
             vec![activatable::create_mention_span(
                 display_text,
                 &url,
                 activatable_manager,
+                theme,
             )]
         }
         Token::InlineCode(text) => {
-            *col_offset += text.len();
+            *col_offset += text.chars().count();
             vec![Span::styled(
                 text,
                 Style::default().fg(Color::White).bg(Color::DarkGray),
             )]
         }
         Token::Strikethrough(text) => {
-            *col_offset += text.len();
+            *col_offset += text.chars().count();
             vec![Span::styled(
                 text,
                 Style::default().add_modifier(Modifier::CROSSED_OUT),
             )]
         }
         Token::Underline(text) => {
-            *col_offset += text.len();
+            *col_offset += text.chars().count();
             vec![Span::styled(
                 text,
                 Style::default().add_modifier(Modifier::UNDERLINED),
@@ -197,15 +356,21 @@ fn apply_block_styling(
     post: &parser::Post,
     activatable_manager: Option<&ActivatableManager>,
     collector: &ActivatableCollector,
-) -> Vec<Vec<Span<'static>>> {
+    code_line_numbers: bool,
+) -> (Vec<Vec<Span<'static>>>, Vec<Option<usize>>) {
     let mut styled_lines = lines;
+    // Lines to insert after a given line and lines hidden in collapsed blocks,
+    // both applied once all blocks are styled so line numbers stay valid
+    let mut inserted_lines: Vec<(usize, Vec<Vec<Span<'static>>>)> = vec![];
+    let mut hidden_lines: HashSet<usize> = HashSet::new();
 
     for block in blocks {
         match block {
             ActivatableElement::Block(org_block) => {
                 let start_line = block.start_line();
                 let end_line = block.end_line();
-                let is_collapsed = block.is_collapsed();
+                let is_collapsed = block.is_collapsed()
+                    || activatable_manager.is_some_and(|manager| manager.is_block_collapsed(start_line));
 
                 if is_collapsed {
                     // Replace the block lines with a single collapsed line
@@ -218,7 +383,7 @@ fn apply_block_styling(
                         true,
                         start_line,
                         0,
-                        summary.len(),
+                        summary.chars().count(),
                         start_line,
                     );
 
@@ -231,15 +396,9 @@ fn apply_block_styling(
                         );
                         
                         styled_lines[start_line] = vec![collapsed_span];
-                        
-                        // Remove the lines that are collapsed (from start+1 to end)
-                        let lines_to_remove = (start_line + 1).min(styled_lines.len())
-                            ..=end_line.min(styled_lines.len().saturating_sub(1));
-                        for _ in lines_to_remove.clone() {
-                            if start_line + 1 < styled_lines.len() {
-                                styled_lines.remove(start_line + 1);
-                            }
-                        }
+
+                        // Hide the lines that are collapsed (from start+1 to end)
+                        hidden_lines.extend(start_line + 1..=end_line);
                     }
                 } else {
                     // Block is expanded - add styling to indicate it's a block
@@ -251,13 +410,49 @@ fn apply_block_styling(
                         start_line,
                         0,
                         if start_line < styled_lines.len() {
-                            styled_lines[start_line].iter().map(|s| s.content.len()).sum()
+                            styled_lines[start_line].iter().map(|s| s.content.chars().count()).sum()
                         } else {
                             0
                         },
                         start_line,
                     );
 
+                    // Highlight the code between the begin and end lines of src blocks in known languages
+                    let syntax = org_block.attributes.as_deref()
+                        .filter(|_| org_block.block_type == "src")
+                        .and_then(|attributes| attributes.split_whitespace().next())
+                        .and_then(highlight::find_syntax);
+                    if let Some(syntax) = syntax {
+                        for line in styled_lines.iter_mut().take(end_line).skip(start_line + 1) {
+                            let text: String = line.iter().map(|span| span.content.as_ref()).collect();
+                            *line = highlight::highlight_line(&text, syntax);
+                        }
+                    }
+
+                    // Quotations get a bar in front and italic text, the bar moves their links right
+                    if org_block.block_type == "quote" {
+                        for (line_num, line) in styled_lines.iter_mut().enumerate().take(end_line).skip(start_line + 1) {
+                            for span in line.iter_mut() {
+                                span.style = span.style.add_modifier(Modifier::ITALIC);
+                            }
+                            let bar = Span::styled("▎ ", Style::default().fg(Color::DarkGray));
+                            shift_collected_elements(collector, line_num, bar.content.chars().count());
+                            line.insert(0, bar);
+                        }
+                    }
+
+                    // Numbers go in front of the spans, after the links and mentions were collected,
+                    // which move right by the width of the gutter
+                    if code_line_numbers && matches!(org_block.block_type.as_str(), "src" | "example") {
+                        let code_lines = styled_lines.iter_mut().enumerate().take(end_line).skip(start_line + 1);
+                        let width = end_line.saturating_sub(start_line + 1).to_string().len();
+                        for (number, (line_num, line)) in code_lines.enumerate() {
+                            let gutter = line_number_span(number + 1, width);
+                            shift_collected_elements(collector, line_num, gutter.content.chars().count());
+                            line.insert(0, gutter);
+                        }
+                    }
+
                     // Apply block focus styling if focused
                     if let Some(manager) = activatable_manager {
                         if manager.is_block_focused(start_line) {
@@ -277,26 +472,35 @@ fn apply_block_styling(
                 let start_line = block.start_line();
                 let end_line = block.end_line();
 
-                // Check if we have existing poll data in the manager
-                let (vote_counts, total_votes, status) = if let Some(manager) = activatable_manager {
-                    manager.get_poll_data_for_line(start_line)
-                        .unwrap_or((None, 0, "Unknown".to_string()))
-                } else {
-                    (None, 0, "Unknown".to_string())
-                };
+                // The status comes from the post itself, the manager only knows it once the post was processed
+                let status = activatable::poll_status(post).to_string();
+                let (vote_counts, total_votes) = activatable_manager
+                    .and_then(|manager| manager.get_poll_data_for_line(start_line))
+                    .map_or((None, 0), |(vote_counts, total_votes, _)| (vote_counts, total_votes));
+                let closed = status == activatable::POLL_CLOSED;
+
+                // Show vote results as bars below the poll once they were counted, or at least that it is closed
+                if let Some(counts) = &vote_counts {
+                    inserted_lines.push((end_line, poll_result_lines(counts, total_votes, &status)));
+                } else if closed {
+                    let closed_style = Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC);
+                    inserted_lines.push((end_line, vec![vec![Span::styled("  Poll closed", closed_style)]]));
+                }
+
+                // Dim the options of closed polls
+                if closed {
+                    for line in styled_lines.iter_mut().take(end_line + 1).skip(start_line) {
+                        for span in line {
+                            span.style = span.style.fg(Color::DarkGray);
+                        }
+                    }
+                }
 
                 // Polls are not collapsible, just add them as activatable elements
                 // Add poll to collector for vote counting activation
                 if let Ok(mut elements) = collector.lock() {
-                    // Get a short title from the post content (first 30 characters)
-                    let post_title = {
-                        let content = post.content();
-                        if content.len() > 30 {
-                            format!("{}...", &content[..30])
-                        } else {
-                            content.to_string()
-                        }
-                    };
+                    // Get a short title from the post content
+                    let post_title = formatting::truncate_chars(post.content(), activatable::POLL_TITLE_CHARS);
                     
                     elements.push((
                         super::super::activatable::ActivatableType::Poll { 
@@ -308,7 +512,7 @@ fn apply_block_styling(
                         start_line,
                         0,
                         if start_line < styled_lines.len() {
-                            styled_lines[start_line].iter().map(|s| s.content.len()).sum()
+                            styled_lines[start_line].iter().map(|s| s.content.chars().count()).sum()
                         } else {
                             0
                         },
@@ -316,7 +520,7 @@ fn apply_block_styling(
                     ));
                 }
 
-                // Apply poll focus styling and add vote count information if available
+                // Apply poll focus styling
                 if let Some(manager) = activatable_manager {
                     if manager.is_poll_focused(start_line) {
                         // Apply subtle background to all lines in the poll
@@ -328,42 +532,130 @@ fn apply_block_styling(
                             }
                         }
                     }
-                    
-                    // If poll results are available, append them to the display
-                    if let Some(poll_info) = manager.get_poll_display_info(start_line) {
-                        // Add the poll results as additional lines after the poll content
-                        let poll_lines: Vec<&str> = poll_info.lines().collect();
-                        for (i, info_line) in poll_lines.iter().enumerate().skip(1) { // Skip first line (poll question)
-                            let result_line_idx = end_line + i;
-                            if result_line_idx < styled_lines.len() {
-                                // Insert vote count information
-                                styled_lines[result_line_idx].push(Span::styled(
-                                    format!(" [{}]", info_line),
-                                    Style::default().fg(Color::Cyan).add_modifier(Modifier::ITALIC)
-                                ));
-                            }
-                        }
-                    }
                 }
             }
         }
     }
 
-    styled_lines
+    // Walk the original lines, dropping hidden ones and adding inserted ones after their line.
+    // Also note where each original line ended up, None for hidden ones.
+    inserted_lines.sort_by_key(|(after_line, _)| *after_line);
+    let mut inserted_lines = inserted_lines.into_iter().peekable();
+    let last_line = styled_lines.len().saturating_sub(1);
+    let mut result = vec![];
+    let mut line_map = vec![];
+    for (index, line) in styled_lines.into_iter().enumerate() {
+        if hidden_lines.contains(&index) {
+            line_map.push(None);
+        } else {
+            line_map.push(Some(result.len()));
+            result.push(line);
+        }
+        while let Some((_, lines)) = inserted_lines.next_if(|(after_line, _)| *after_line <= index || index == last_line) {
+            result.extend(lines);
+        }
+    }
+    result.extend(inserted_lines.flat_map(|(_, lines)| lines));
+
+    (result, line_map)
+}
+
+/// Move the elements collected on a content line right by the width of what was put in front of it
+fn shift_collected_elements(collector: &ActivatableCollector, line: usize, width: usize) {
+    if let Ok(mut elements) = collector.lock() {
+        for (_, element_line, start_col, end_col, _) in elements.iter_mut() {
+            if *element_line == line {
+                *start_col += width;
+                *end_col += width;
+            }
+        }
+    }
+}
+
+/// Dimmed, right-aligned gutter number of a code line
+fn line_number_span(number: usize, width: usize) -> Span<'static> {
+    // Non-breaking spaces, as wrapping trims leading ordinary ones
+    let gutter = format!("{number:>width$} │ ").replacen(' ', "\u{a0}", width - number.to_string().len());
+    Span::styled(gutter, Style::default().fg(Color::DarkGray))
+}
+
+/// Width of the poll result bars, in characters
+const POLL_BAR_WIDTH: usize = 20;
+
+/// Render poll results as one bar per option, proportional to its share of the votes
+fn poll_result_lines(vote_counts: &[(String, usize)], total_votes: usize, status: &str) -> Vec<Vec<Span<'static>>> {
+    let result_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::ITALIC);
+    let mut lines = vec![];
+
+    if total_votes == 0 {
+        lines.push(vec![Span::styled(format!("  No votes yet | Status: {status}"), result_style)]);
+        return lines;
+    }
+
+    let max_votes = vote_counts.iter().map(|(_, votes)| *votes).max().unwrap_or(0);
+    let label_width = vote_counts.iter().map(|(option, _)| option.chars().count()).max().unwrap_or(0);
+
+    for (option, votes) in vote_counts {
+        let filled = (votes * POLL_BAR_WIDTH + total_votes / 2) / total_votes;
+        let bar = format!("{}{}", "█".repeat(filled), "░".repeat(POLL_BAR_WIDTH.saturating_sub(filled)));
+        let percent = votes * 100 / total_votes;
+
+        let bar_style = if *votes == max_votes && max_votes > 0 {
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+
+        lines.push(vec![
+            Span::raw(format!("  {option:<label_width$} ")),
+            Span::styled(bar, bar_style),
+            Span::styled(format!(" {votes} ({percent}%)"), result_style),
+        ]);
+    }
+
+    lines.push(vec![Span::styled(format!("  Total: {total_votes} votes | Status: {status}"), result_style)]);
+    lines
+}
+
+/// Position in content longer than the pane, e.g. " line 12/80 · 15% "
+fn scroll_indicator(scroll: usize, max_scroll: usize, total_rows: usize) -> String {
+    format!(" line {}/{total_rows} · {}% ", scroll + 1, scroll * 100 / max_scroll)
+}
+
+/// Layout of the rendered post content, reported back to the app for scrolling and mouse handling
+pub struct RenderedContent {
+    /// Inner area the content text was rendered in
+    pub area: Rect,
+    /// Largest useful scroll offset, in rows after wrapping
+    pub max_scroll: usize,
+    /// First rows of the lines matching the filter, after wrapping
+    pub match_rows: Vec<usize>,
+}
+
+/// Content of a post being written, rendered the way it will be read
+pub fn preview_lines(content: &str, theme: &Theme, code_line_numbers: bool) -> Vec<Line<'static>> {
+    let post = parser::Post::new(String::new(), content.to_string());
+    // Nothing in the preview can be focused or activated, so its elements are thrown away
+    let collector = ActivatableManager::create_collector();
+    process_post_tokens(&post, &collector, None, theme, code_line_numbers, None).0
 }
 
 /// Draw the current post content
-pub fn draw_post_content(f: &mut Frame, area: Rect, post: Option<&parser::Post>, scroll_offset: usize, collector: &ActivatableCollector, activatable_manager: Option<&ActivatableManager>) {
+pub fn draw_post_content(f: &mut Frame, area: Rect, app: &TUI) -> Option<RenderedContent> {
+    let collector = &app.activatable_collector;
+    let activatable_manager = Some(&app.activatable_manager);
+    let theme = &app.theme;
+
     // Clear the collector for new content
     if let Ok(mut elements) = collector.lock() {
         elements.clear();
     }
     
-    if let Some(post) = post {
+    if let Some(post) = app.current_post() {
         // Create header with post metadata
         let author = post.author().as_ref().map(|s| s.as_str()).unwrap_or("unknown");
         let time_str = if let Some(time) = post.time() {
-            time.format("%Y-%m-%d %H:%M").to_string()
+            formatting::format_post_time(time, app.config.relative_timestamps, "%Y-%m-%d %H:%M", app.config.timezone)
         } else {
             "no time".to_string()
         };
@@ -371,11 +663,12 @@ pub fn draw_post_content(f: &mut Frame, area: Rect, post: Option<&parser::Post>,
         let mut header_lines = vec![
             Line::from(vec![
                 Span::styled("Author: ", Style::default().fg(Color::Gray)),
-                Span::styled(author, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::styled(super::post_list::own_post_marker(app, post), Style::default().fg(Color::Cyan)),
+                Span::styled(author, Style::default().fg(theme.author).add_modifier(Modifier::BOLD)),
             ]),
             Line::from(vec![
                 Span::styled("Time: ", Style::default().fg(Color::Gray)),
-                Span::styled(time_str, Style::default().fg(Color::Blue)),
+                Span::styled(time_str, Style::default().fg(theme.timestamp)),
             ]),
             Line::from(vec![
                 Span::styled("ID: ", Style::default().fg(Color::Gray)),
@@ -388,16 +681,28 @@ pub fn draw_post_content(f: &mut Frame, area: Rect, post: Option<&parser::Post>,
             if !tags.is_empty() {
                 header_lines.push(Line::from(vec![
                     Span::styled("Tags: ", Style::default().fg(Color::Gray)),
-                    Span::styled(tags.join(" "), Style::default().fg(Color::Cyan)),
+                    Span::styled(tags.join(" "), Style::default().fg(theme.tag)),
                 ]));
             }
         }
 
-        // Add reply info if present
+        // Add reply info if present, quoting the parent post when it is loaded
         if let Some(reply_to) = post.reply_to() {
+            let reply_span = match app.reply_parent(post) {
+                Some(parent) => {
+                    let parent_author = parent.author().as_deref().unwrap_or("unknown");
+                    let first_line = parent.content().lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or("");
+                    let quote = format!("{parent_author}: {first_line}");
+                    // Keep the quote on one line of the header
+                    let max_width = (area.width as usize).saturating_sub(2 + "Reply to: ".len());
+                    let quote = formatting::truncate_chars(&quote, max_width);
+                    Span::styled(quote, Style::default().fg(Color::Gray).add_modifier(Modifier::DIM | Modifier::ITALIC))
+                }
+                None => Span::styled(reply_to, Style::default().fg(Color::Magenta)),
+            };
             header_lines.push(Line::from(vec![
                 Span::styled("Reply to: ", Style::default().fg(Color::Gray)),
-                Span::styled(reply_to, Style::default().fg(Color::Magenta)),
+                reply_span,
             ]));
         }
 
@@ -424,19 +729,214 @@ pub fn draw_post_content(f: &mut Frame, area: Rect, post: Option<&parser::Post>,
 
         f.render_widget(header, content_chunks[0]);
 
-        // Process post content using the new token-based approach
-        let content_lines = process_post_tokens(post, collector, activatable_manager, scroll_offset);
+        // The raw view shows the org source verbatim, keeping its indentation
+        let raw_view = app.is_raw_view_shown();
+        let title = if raw_view { "Content (raw org source)" } else { "Content" };
+        let mut content_block = Block::default().borders(Borders::ALL).title(title);
+        let content_area = content_block.inner(content_chunks[1]);
 
-        let content = Paragraph::new(content_lines)
-            .block(Block::default().borders(Borders::ALL).title("Content"))
-            .wrap(Wrap { trim: true });
+        let (content, match_rows) = if raw_view {
+            let raw_lines: Vec<Line> = post.content().lines().map(Line::raw).collect();
+            let match_rows = match_rows(&raw_lines, content_area.width, false, app.filter_matcher());
+            (Paragraph::new(raw_lines).wrap(Wrap { trim: false }), match_rows)
+        } else {
+            // Process post content using the new token-based approach
+            let (content_lines, line_map) =
+                process_post_tokens(post, collector, activatable_manager, theme, app.config.code_line_numbers, app.filter_matcher());
+            place_collected_elements(collector, &line_map, &content_lines, content_area.width);
+            let match_rows = match_rows(&content_lines, content_area.width, true, app.filter_matcher());
+            (Paragraph::new(content_lines).wrap(Wrap { trim: true }), match_rows)
+        };
+
+        // Scroll by rendered rows, so long wrapped lines can be scrolled through completely
+        let total_rows = content.line_count(content_area.width);
+        let max_scroll = total_rows.saturating_sub(content_area.height as usize);
+        let scroll = app.navigator.scroll_offset.min(max_scroll) as u16;
+        if max_scroll > 0 {
+            content_block = content_block.title_top(Line::from(scroll_indicator(scroll as usize, max_scroll, total_rows)).right_aligned());
+        }
+        let content = content.block(content_block).scroll((scroll, 0));
 
         f.render_widget(content, content_chunks[1]);
+        Some(RenderedContent { area: content_area, max_scroll, match_rows })
     } else {
         let no_posts = Paragraph::new("No posts available")
             .block(Block::default().borders(Borders::ALL).title("Content"))
             .style(Style::default().fg(Color::Gray));
 
         f.render_widget(no_posts, area);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Content line, start and end column of the elements collected while processing the content
+    fn collected_positions(content: &str) -> Vec<(usize, usize, usize)> {
+        let post = parser::Post::new(String::new(), content.to_string());
+        let collector = ActivatableManager::create_collector();
+        process_post_tokens(&post, &collector, None, &Theme::default(), false, None);
+        let elements = collector.lock().unwrap();
+        elements.iter().map(|(_, line, start_col, end_col, _)| (*line, *start_col, *end_col)).collect()
+    }
+
+    #[test]
+    fn link_columns_ignore_carriage_returns() {
+        let positions = collected_positions("Intro [[https://example.org][first]]\r\nSee [[https://example.com][site]] now\r\n");
+        assert_eq!(positions, vec![(0, 6, 11), (1, 4, 8)]);
+    }
+
+    #[test]
+    fn link_after_accented_text_starts_at_its_char_column() {
+        let collector = ActivatableManager::create_collector();
+        let theme = Theme::default();
+        let mut col = 0;
+        let tokens = [
+            Token::PlainText("Café déjà ".to_string()),
+            Token::Link { url: "https://example.com".to_string(), description: Some("menú".to_string()) },
+        ];
+        for token in tokens {
+            token_to_spans(token, &collector, None, &theme, None, 0, &mut col);
+        }
+
+        let elements = collector.lock().unwrap();
+        let (_, line, start_col, end_col, _) = elements[0];
+        assert_eq!((line, start_col, end_col), (0, 10, 14));
+        assert_eq!(col, 14);
+    }
+
+    const WIDTH: u16 = 20;
+
+    /// Lines of the content with the collected elements placed on their rendered rows
+    fn placed_content(content: &str, collector: &ActivatableCollector, code_line_numbers: bool) -> Vec<Line<'static>> {
+        let post = parser::Post::new(String::new(), content.to_string());
+        let (lines, line_map) = process_post_tokens(&post, collector, None, &Theme::default(), code_line_numbers, None);
+        place_collected_elements(collector, &line_map, &lines, WIDTH);
+        lines
+    }
+
+    /// Text of each row the lines are wrapped to
+    fn rendered_rows(lines: &[Line<'static>]) -> Vec<String> {
+        let paragraph = Paragraph::new(lines.to_vec()).wrap(Wrap { trim: true });
+        let area = Rect::new(0, 0, WIDTH, paragraph.line_count(WIDTH) as u16);
+        let mut buffer = Buffer::empty(area);
+        paragraph.render(area, &mut buffer);
+        (0..area.height)
+            .map(|row| (0..WIDTH).map(|col| buffer.cell((col, row)).unwrap().symbol()).collect())
+            .collect()
+    }
+
+    fn shown_text(rows: &[String], element: &activatable::ActivatablePosition) -> String {
+        rows[element.line].chars().skip(element.start_col).take(element.end_col - element.start_col).collect()
+    }
+
+    #[test]
+    fn link_on_a_wrapped_row_is_placed_where_it_is_rendered() {
+        let collector = ActivatableManager::create_collector();
+        let lines = placed_content("alpha beta gamma delta [[https://example.com][zeta link]] eta", &collector, false);
+        let rows = rendered_rows(&lines);
+        let mut manager = ActivatableManager::new();
+        manager.update_from_collector(&collector);
+
+        assert!(manager.focus_next());
+        let link = manager.focused_element().unwrap().clone();
+        assert!(link.line > 0);
+        assert_eq!(shown_text(&rows, &link), "zeta link");
+        // A click on the link's row hits it, the row above doesn't
+        assert!(manager.element_at(link.line, link.start_col + 1).is_some());
+        assert!(manager.element_at(link.line - 1, link.start_col + 1).is_none());
+    }
+
+    #[test]
+    fn link_in_a_numbered_code_block_is_placed_after_the_gutter() {
+        let collector = ActivatableManager::create_collector();
+        let lines = placed_content("#+begin_src rust\nlet a = [[https://y.org][yy]];\n#+end_src", &collector, true);
+        let rows = rendered_rows(&lines);
+        let mut manager = ActivatableManager::new();
+        manager.update_from_collector(&collector);
+
+        assert!(rows[1].starts_with("1 │ let a = yy;"));
+        assert!(manager.element_at(1, 8).is_none());
+        let id = manager.element_at(1, 12).unwrap();
+        assert!(manager.focus_element(id));
+        assert_eq!(shown_text(&rows, manager.focused_element().unwrap()), "yy");
+    }
+
+    #[test]
+    fn link_in_a_quote_is_placed_after_the_bar() {
+        let collector = ActivatableManager::create_collector();
+        let lines = placed_content("#+begin_quote\nsee [[https://x.org][link]]\n#+end_quote", &collector, false);
+        let rows = rendered_rows(&lines);
+        let mut manager = ActivatableManager::new();
+        manager.update_from_collector(&collector);
+
+        assert!(rows[1].starts_with("▎ see link"));
+        assert!(manager.element_at(1, 4).is_none());
+        let id = manager.element_at(1, 6).unwrap();
+        assert!(manager.focus_element(id));
+        assert_eq!(shown_text(&rows, manager.focused_element().unwrap()), "link");
+    }
+
+    #[test]
+    fn focusing_a_link_below_the_fold_scrolls_to_it() {
+        let height = 10;
+        let mut content: String = (1..=30).map(|number| format!("Line {number}\n")).collect();
+        content.push_str("a long last line before [[https://example.com][the link]] at the end");
+        let collector = ActivatableManager::create_collector();
+        let lines = placed_content(&content, &collector, false);
+        let rows = rendered_rows(&lines);
+        let max_scroll = rows.len() - height;
+
+        let mut manager = ActivatableManager::new();
+        manager.update_from_collector(&collector);
+        assert!(manager.focus_next());
+        let link = manager.focused_element().unwrap().clone();
+        assert_eq!(shown_text(&rows, &link), "the link");
+
+        let mut navigator = crate::tui::navigation::Navigator::new();
+        navigator.scroll_to_row(link.line, height, max_scroll);
+        assert!(navigator.scroll_offset > 0);
+        assert!((navigator.scroll_offset..navigator.scroll_offset + height).contains(&link.line));
+        assert!(navigator.scroll_offset <= max_scroll);
+    }
+
+    /// Text of the rows of the buffer in the area
+    fn buffer_rows(buffer: &Buffer, area: Rect) -> Vec<String> {
+        (area.top()..area.bottom())
+            .map(|row| (area.left()..area.right()).map(|col| buffer.cell((col, row)).unwrap().symbol()).collect())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn scrolling_a_long_line_is_clamped_to_its_wrapped_rows() {
+        // 400 chars, five rows in an 80 column pane
+        let line: String = (0..80).map(|word| format!("w{word:03} ")).collect();
+        let mut app = crate::test_support::tui(&[crate::test_support::org_post("2025-01-01T10:00:00+0000", None, line.trim_end())]).await;
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(82, 10)).unwrap();
+        let mut draw = |app: &TUI| {
+            let mut rendered = None;
+            let frame = terminal.draw(|f| rendered = draw_post_content(f, f.area(), app)).unwrap();
+            let rendered = rendered.unwrap();
+            (buffer_rows(frame.buffer, rendered.area), rendered)
+        };
+
+        let (_, rendered) = draw(&app);
+        assert_eq!(rendered.area.width, 80);
+        assert_eq!(rendered.max_scroll, 5 - rendered.area.height as usize);
+
+        // Scrolling down stops at the last row, which shows the end of the line
+        for _ in 0..10 {
+            app.navigator.scroll_down(rendered.max_scroll);
+        }
+        assert_eq!(app.navigator.scroll_offset, rendered.max_scroll);
+        let (rows, _) = draw(&app);
+        assert!(rows.last().unwrap().contains("w079"));
+
+        // An offset left from a longer post is clamped when drawing
+        app.navigator.scroll_offset = 100;
+        let (clamped_rows, _) = draw(&app);
+        assert_eq!(clamped_rows, rows);
     }
 }
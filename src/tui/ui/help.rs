@@ -8,8 +8,8 @@ use ratatui::{
     Frame,
 };
 
-/// Draw the help overlay
-pub fn draw_help(f: &mut Frame, area: Rect, scroll_offset: u16) {
+/// Draw the help overlay, returning the largest useful scroll offset
+pub fn draw_help(f: &mut Frame, area: Rect, scroll_offset: u16) -> u16 {
     let help_text = vec![
         Line::from(""),
         Line::from(Span::styled("Org-Social TUI Help", Style::default().add_modifier(Modifier::BOLD).fg(Color::Yellow))),
@@ -25,26 +25,46 @@ pub fn draw_help(f: &mut Frame, area: Rect, scroll_offset: u16) {
         Line::from("  G    - Go to last post"),
         Line::from(""),
         Line::from("View Modes:"),
-        Line::from("  t    - Toggle between modes: List -> Threaded -> Notifications -> List"),
+        Line::from("  t    - Toggle between modes: List -> Threaded -> Notifications -> Bookmarks -> Own Posts -> List"),
         Line::from("  List View: Shows all posts chronologically"),
         Line::from("  Threaded View: Shows posts organized by conversations"),
         Line::from("  Notifications View: Shows mentions and replies targeted at the user"),
+        Line::from("  Bookmarks View: Shows bookmarked posts (marked with ★ in the list view)"),
+        Line::from("  Own Posts View: Shows only the posts from your social file"),
         Line::from(""),
         Line::from("Actions:"),
         Line::from("  r    - Reply to current post"),
         Line::from("  n    - Create new post"),
-        Line::from("  v    - Count votes for poll (threaded view only)"),
+        Line::from("  R    - Refresh the feed"),
+        Line::from("  v    - Count votes for poll"),
+        Line::from("  b    - Bookmark the current post, or remove its bookmark"),
+        Line::from("  M    - Mark all posts in the current view as read (read posts are dimmed)"),
+        Line::from("  y    - Copy the post content to the clipboard"),
+        Line::from("  Y    - Copy the post URL (source#id) to the clipboard"),
+        Line::from("  o    - Toggle the raw org source of the post, until moving to another post"),
+        Line::from("  c    - Collapse or expand the selected thread (threaded view)"),
+        Line::from("  z    - Collapse all blocks of the post, or expand them if all are collapsed"),
+        Line::from("  H    - Hide or show reactions and poll votes"),
+        Line::from("  m    - Mute or unmute the post's author (press U first to reach muted posts)"),
+        Line::from("  U    - Show or hide the posts of muted authors and with muted keywords"),
+        Line::from("  T    - Show all notifications, mentions only or replies only (notifications view)"),
+        Line::from("  f    - Focus mode: hide the post list and give the content the full width"),
+        Line::from("  p    - Show the profile of the post's author (Esc to close)"),
+        Line::from("  /    - Filter the list and threaded views as you type (Enter keeps it, Esc clears it)"),
+        Line::from("  n/N  - Next/previous filter match, while a filter is applied"),
         Line::from("  q    - Quit application"),
         Line::from(""),
         Line::from("Hyperlinks/Mentions/Blocks:"),
         Line::from("  l    - Navigate to next link/mention/block"),
         Line::from("  L    - Navigate to previous link/mention/block"),
         Line::from("  Enter - Depending on the type:"),
-        Line::from("    Link: Open in browser"),
-        Line::from("    Mention: Open user's social.org in browser"),
+        Line::from("    Link: Open in browser (copied to clipboard if that fails)"),
+        Line::from("    Mention: Show the user's posts, Esc goes back to the feed"),
         Line::from("    Block: Toggle block"),
+        Line::from("  Mouse - Left click activates, wheel scrolls the content pane"),
         Line::from(""),
         Line::from("Other:"),
+        Line::from("  :/   - Jump to a post by its ID"),
         Line::from("  h/?  - Show/hide this help"),
         Line::from("  Esc  - Cancel current action"),
         Line::from(""),
@@ -52,17 +72,28 @@ pub fn draw_help(f: &mut Frame, area: Rect, scroll_offset: u16) {
         Line::from("  Type to compose reply"),
         Line::from("  Enter/Shift+Enter - Add newline"),
         Line::from("  Ctrl+S - Submit reply"),
+        Line::from("  Ctrl+E - Edit content in $EDITOR"),
+        Line::from("  Ctrl+Z/Ctrl+Y - Undo/redo changes to the content"),
+        Line::from("  Ctrl+Left/Ctrl+Right - Move the cursor by word"),
+        Line::from("  Ctrl+W - Delete the previous word"),
+        Line::from("  @nick - Complete a followed nick, Tab/Enter inserts the mention, Esc closes"),
         Line::from("  Tab/Shift+Tab - Switch fields"),
         Line::from("  F1 - Remove last tag"),
-        Line::from("  Esc - Cancel reply"),
+        Line::from("  Esc - Cancel reply, asking first if anything was written"),
         Line::from(""),
         Line::from("In New Post Mode:"),
         Line::from("  Type to compose post"),
         Line::from("  Enter/Shift+Enter - Add newline/Confirm tags"),
         Line::from("  Ctrl+S - Submit post"),
+        Line::from("  Ctrl+E - Edit content in $EDITOR"),
+        Line::from("  Ctrl+Z/Ctrl+Y - Undo/redo changes to the content"),
+        Line::from("  Ctrl+Left/Ctrl+Right - Move the cursor by word"),
+        Line::from("  Ctrl+W - Delete the previous word"),
+        Line::from("  @nick - Complete a followed nick, Tab/Enter inserts the mention, Esc closes"),
+        Line::from("  Ctrl+P - Show/hide the rendered preview"),
         Line::from("  Tab/Shift+Tab - Switch fields"),
         Line::from("  F1 - Remove last tag"),
-        Line::from("  Esc - Cancel post"),
+        Line::from("  Esc - Cancel post, asking first if anything was written"),
         Line::from(""),
         Line::from(Span::styled("Press h or Esc to close help", Style::default().fg(Color::Green))),
     ];
@@ -87,4 +118,5 @@ pub fn draw_help(f: &mut Frame, area: Rect, scroll_offset: u16) {
         .style(Style::default().bg(Color::Black));
 
     f.render_widget(help, help_area);
+    max_scroll
 }
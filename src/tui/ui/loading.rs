@@ -0,0 +1,24 @@
+//! Loading screen shown while remote feeds are fetched.
+
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Style},
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Draw the number of feeds fetched so far, centered on the screen
+pub fn draw_fetch_progress(f: &mut Frame, fetched: usize, total: usize) {
+    let [_, row, _] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(f.area());
+
+    let progress = Paragraph::new(Line::from(format!("Fetching {fetched}/{total} feeds…")))
+        .style(Style::default().fg(Color::Yellow))
+        .centered();
+    f.render_widget(progress, row);
+}
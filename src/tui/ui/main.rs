@@ -1,92 +1,130 @@
 //! Main UI layout and coordination.
 
-use super::super::activatable::{ActivatableCollector, ActivatableManager};
-use super::super::modes::{AppMode, ViewMode};
-use super::super::navigation::Navigator;
-use super::{content, help, new_post, poll_vote, post_list, reply, status};
-use org_social_lib_rs::{new_post as new_post_module, notifications, parser, reply as reply_module, threading};
+use super::super::app::TUI;
+use super::super::modes::AppMode;
+use super::{completion, confirm, content, help, new_post, poll_vote, post_list, profile, reply, status};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
 
 /// Draw the main UI based on current mode
-pub fn draw_ui(
-    f: &mut Frame,
-    mode: &AppMode,
-    view_mode: &ViewMode,
-    posts: &[parser::Post],
-    notification_feed: &notifications::NotificationFeed,
-    thread_view: &threading::ThreadView,
-    navigator: &Navigator,
-    current_post: Option<&parser::Post>,
-    reply_state: &Option<reply_module::ReplyState>,
-    new_post_state: &Option<new_post_module::NewPostState>,
-    poll_vote_state: &Option<poll_vote::PollVoteState>,
-    status_message: &Option<String>,
-    cursor_visible: bool,
-    help_scroll: u16,
-    collector: &ActivatableCollector,
-    activatable_manager: Option<&ActivatableManager>,
-) {
+pub fn draw_ui(f: &mut Frame, app: &TUI) {
     let size = f.area();
 
-    match mode {
+    match app.mode {
         AppMode::Help => {
-            help::draw_help(f, size, help_scroll);
+            app.help_max_scroll.set(help::draw_help(f, size, app.help_scroll));
         }
         AppMode::Reply => {
-            if let Some(reply_state) = reply_state {
-                reply::draw_reply_window(f, size, reply_state, cursor_visible);
+            if let Some(reply_state) = &app.reply_state {
+                let content_field = reply::draw_reply_window(f, size, reply_state, app.cursor_visible, &app.status_message, app.config.post_char_limit);
+                if let Some(completion) = &app.nick_completion {
+                    completion::draw_nick_completion(f, content_field, completion);
+                }
             }
         }
         AppMode::NewPost => {
-            if let Some(new_post_state) = new_post_state {
-                new_post::draw_new_post_window(f, size, new_post_state, cursor_visible);
+            if let Some(new_post_state) = &app.new_post_state {
+                let content_field = new_post::draw_new_post_window(f, size, app, new_post_state, app.cursor_visible, &app.status_message);
+                if let Some(completion) = &app.nick_completion {
+                    completion::draw_nick_completion(f, content_field, completion);
+                }
             }
         }
         AppMode::PollVote => {
-            if let Some(poll_vote_state) = poll_vote_state {
+            if let Some(poll_vote_state) = &app.poll_vote_state {
                 poll_vote::render_poll_vote(f, size, poll_vote_state);
             }
         }
+        AppMode::ConfirmDiscard => {
+            if let Some(reply_state) = &app.reply_state {
+                reply::draw_reply_window(f, size, reply_state, false, &None, app.config.post_char_limit);
+            } else if let Some(new_post_state) = &app.new_post_state {
+                new_post::draw_new_post_window(f, size, app, new_post_state, false, &None);
+            }
+            confirm::draw_confirm_discard(f, size);
+        }
+        AppMode::Profile => {
+            draw_main_ui(f, size, app);
+            if let Some(author_profile) = &app.profile_view {
+                profile::draw_profile(f, size, author_profile, &app.theme);
+            }
+        }
         _ => {
-            draw_main_ui(f, size, view_mode, posts, notification_feed, thread_view, navigator, current_post, mode, status_message, collector, activatable_manager);
+            draw_main_ui(f, size, app);
         }
     }
 }
 
-fn draw_main_ui(
-    f: &mut Frame,
-    area: Rect,
-    view_mode: &ViewMode,
-    posts: &[parser::Post],
-    notification_feed: &notifications::NotificationFeed,
-    thread_view: &threading::ThreadView,
-    navigator: &Navigator,
-    current_post: Option<&parser::Post>,
-    mode: &AppMode,
-    status_message: &Option<String>,
-    collector: &ActivatableCollector,
-    activatable_manager: Option<&ActivatableManager>,
-) {
+/// Below this width the post list is drawn above the content instead of next to it
+const STACKED_LAYOUT_WIDTH: u16 = 80;
+
+fn draw_main_ui(f: &mut Frame, area: Rect, app: &TUI) {
     // Split the screen into three areas
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(1), Constraint::Length(3)].as_ref())
         .split(area);
 
-    let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
-        .split(main_chunks[0]);
+    // Recomputed on every draw, so resizing the terminal switches between the layouts
+    let direction = if area.width < STACKED_LAYOUT_WIDTH { Direction::Vertical } else { Direction::Horizontal };
+    let list_percent = app.config.list_pane_percent.clamp(10, 90);
+    let content_area = if app.focus_mode {
+        // Focus mode gives the content the full width, the list is hidden but still navigated
+        main_chunks[0]
+    } else {
+        let content_chunks = Layout::default()
+            .direction(direction)
+            .constraints([Constraint::Percentage(list_percent), Constraint::Percentage(100 - list_percent)].as_ref())
+            .split(main_chunks[0]);
 
-    // Draw post list (or notification list)
-    post_list::draw_post_list(f, content_chunks[0], view_mode, posts, notification_feed, thread_view, navigator);
+        // Draw post list (or notification list)
+        post_list::draw_post_list(f, content_chunks[0], app);
+        content_chunks[1]
+    };
 
-    // Draw post content
-    content::draw_post_content(f, content_chunks[1], current_post, navigator.scroll_offset, collector, activatable_manager);
+    // Draw post content, remembering where it went for mouse handling
+    let rendered = content::draw_post_content(f, content_area, app);
+    app.content_area.set(rendered.as_ref().map(|rendered| rendered.area));
+    app.content_max_scroll.set(rendered.as_ref().map_or(0, |rendered| rendered.max_scroll));
+    app.content_match_rows.replace(rendered.map(|rendered| rendered.match_rows).unwrap_or_default());
 
     // Draw status area
-    status::draw_status_area(f, main_chunks[1], mode, view_mode, status_message);
+    status::draw_status_area(f, main_chunks[1], app);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{org_post_with_properties, tui};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn screen(terminal: &mut Terminal<TestBackend>, app: &TUI) -> String {
+        let frame = terminal.draw(|f| draw_ui(f, app)).unwrap();
+        let area = frame.area;
+        (area.top()..area.bottom())
+            .flat_map(|row| (area.left()..area.right()).map(move |col| (col, row)))
+            .map(|position| frame.buffer.cell(position).unwrap().symbol().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn poll_vote_mode_draws_the_poll_vote_screen() {
+        let mut app = tui(&[org_post_with_properties(
+            "2025-01-01T10:00:00+0000",
+            &[("POLL_END", "2099-01-01T00:00:00+0000")],
+            "Tabs or spaces?\n\n- [ ] Tabs\n- [ ] Spaces",
+        )])
+        .await;
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        assert!(!screen(&mut terminal, &app).contains("Vote Options"));
+
+        app.next_hyperlink();
+        app.start_poll_vote();
+        assert!(matches!(app.mode, AppMode::PollVote));
+        let screen = screen(&mut terminal, &app);
+        assert!(screen.contains("Vote Options"));
+        assert!(screen.contains("Spaces"));
+    }
 }
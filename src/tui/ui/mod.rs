@@ -1,11 +1,15 @@
 //! UI module exports.
 
+pub mod completion;
+pub mod confirm;
 pub mod content;
 pub mod help;
+pub mod loading;
 pub mod main;
 pub mod new_post;
 pub mod poll_vote;
 pub mod post_list;
+pub mod profile;
 pub mod reply;
 pub mod status;
 
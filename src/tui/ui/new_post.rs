@@ -1,9 +1,11 @@
 //! New post window UI component.
 
+use super::super::app::TUI;
+use super::content;
 use org_social_lib_rs::new_post;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
@@ -83,8 +85,9 @@ fn render_single_line_with_cursor(text: &str, cursor_pos: usize) -> Line {
     Line::from(line_spans)
 }
 
-/// Draw the new post window overlay
-pub fn draw_new_post_window(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPostState, cursor_visible: bool) {
+/// Draw the new post window overlay, returning the area of the content field
+pub fn draw_new_post_window(f: &mut Frame, area: Rect, app: &TUI, new_post_state: &new_post::NewPostState, cursor_visible: bool, status_message: &Option<String>) -> Rect {
+    let invalid_field = app.invalid_new_post_field.as_ref();
     // Create centered new post window
     let new_post_area = Rect {
         x: area.width / 8,
@@ -115,8 +118,16 @@ pub fn draw_new_post_window(f: &mut Frame, area: Rect, new_post_state: &new_post
         .style(Style::default().bg(Color::Black));
     f.render_widget(header, new_post_chunks[0]);
 
-    // Content field
-    draw_content_field(f, new_post_chunks[1], new_post_state, cursor_visible);
+    // Content field, with the rendered preview next to it when shown
+    let content_field = if app.show_compose_preview {
+        let [content_field, preview] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(new_post_chunks[1]);
+        draw_preview(f, preview, app, &new_post_state.content);
+        content_field
+    } else {
+        new_post_chunks[1]
+    };
+    draw_content_field(f, content_field, new_post_state, invalid_field == Some(&new_post::NewPostField::Content), cursor_visible);
 
     // Tags and Mood side by side
     let tags_mood_chunks = Layout::default()
@@ -134,27 +145,67 @@ pub fn draw_new_post_window(f: &mut Frame, area: Rect, new_post_state: &new_post
         .split(new_post_chunks[3]);
     
     draw_lang_field(f, lang_poll_chunks[0], new_post_state, cursor_visible);
-    draw_poll_end_field(f, lang_poll_chunks[1], new_post_state, cursor_visible);
+    draw_poll_end_field(f, lang_poll_chunks[1], new_post_state, invalid_field == Some(&new_post::NewPostField::PollEnd), cursor_visible);
 
     // Poll Option field
     draw_poll_option_field(f, new_post_chunks[4], new_post_state, cursor_visible);
 
     // Help/Controls
-    let help_text = "Tab/Shift+Tab:switch fields | Enter/Shift+Enter:newline | Ctrl+S:submit | F1:remove last tag | Esc:cancel | n:new post";
+    let help_text = "Tab/Shift+Tab:switch fields | Enter/Shift+Enter:newline | Ctrl+S:submit | F1:remove last tag | Esc:cancel | Ctrl+E:$EDITOR | Ctrl+Z/Ctrl+Y:undo/redo | Ctrl+Left/Right:move by word | Ctrl+W:delete word | Ctrl+P:preview | n:new post";
     let help = Paragraph::new(help_text)
-        .block(Block::default().borders(Borders::ALL).title("Controls"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(controls_title(status_message))
+                .title(length_counter(&new_post_state.content, app.config.post_char_limit)),
+        )
         .wrap(Wrap { trim: true })
         .style(Style::default().bg(Color::Black).fg(Color::Green));
     f.render_widget(help, new_post_chunks[5]);
+    content_field
 }
 
-fn draw_content_field(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPostState, cursor_visible: bool) {
-    let content_title = if new_post_state.current_field == new_post::NewPostField::Content {
-        "Content (ACTIVE)"
+/// Draw the content as it will look in the content pane once posted
+fn draw_preview(f: &mut Frame, area: Rect, app: &TUI, text: &str) {
+    let lines = content::preview_lines(text, &app.theme, app.config.code_line_numbers);
+    let preview = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Preview"))
+        .wrap(Wrap { trim: true })
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(preview, area);
+}
+
+/// Character and line count of the content, shown right-aligned in the controls box title.
+/// Characters are counted like the content cursor moves, one per typed character.
+pub fn length_counter(content: &str, char_limit: usize) -> Line<'static> {
+    let chars = content.chars().count();
+    let lines = if content.is_empty() { 0 } else { content.split('\n').count() };
+    let style = if char_limit > 0 && chars > char_limit {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
     } else {
-        "Content"
+        Style::default().fg(Color::Gray)
     };
-    let content_style = if new_post_state.current_field == new_post::NewPostField::Content {
+    let limit = if char_limit > 0 { format!("/{char_limit}") } else { String::new() };
+    Line::from(Span::styled(format!(" {chars}{limit} chars, {lines} lines "), style)).right_aligned()
+}
+
+/// Title of the controls box, showing the status message if there is one
+pub fn controls_title(status_message: &Option<String>) -> String {
+    match status_message {
+        Some(msg) => format!("Controls - {msg}"),
+        None => "Controls".to_string(),
+    }
+}
+
+fn draw_content_field(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPostState, invalid: bool, cursor_visible: bool) {
+    let content_title = match (invalid, new_post_state.current_field == new_post::NewPostField::Content) {
+        (true, _) => "Content (INVALID)",
+        (false, true) => "Content (ACTIVE)",
+        (false, false) => "Content",
+    };
+    let content_style = if invalid {
+        Style::default().bg(Color::Black).fg(Color::Red)
+    } else if new_post_state.current_field == new_post::NewPostField::Content {
         Style::default().bg(Color::Black).fg(Color::Yellow)
     } else {
         Style::default().bg(Color::Black)
@@ -245,11 +296,11 @@ fn draw_mood_field(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPost
     let mood_line = if new_post_state.mood.is_empty() {
         if new_post_state.current_field == new_post::NewPostField::Mood && cursor_visible {
             Line::from(vec![
-                Span::styled("Your mood", Style::default().fg(Color::Gray)),
+                Span::styled("Your mood, e.g. :smile:", Style::default().fg(Color::Gray)),
                 Span::styled("█", Style::default().fg(Color::White).bg(Color::Gray)),
             ])
         } else {
-            Line::from(Span::styled("Your mood", Style::default().fg(Color::Gray)))
+            Line::from(Span::styled("Your mood, e.g. :smile:", Style::default().fg(Color::Gray)))
         }
     } else {
         if new_post_state.current_field == new_post::NewPostField::Mood && cursor_visible {
@@ -300,13 +351,15 @@ fn draw_lang_field(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPost
     f.render_widget(lang, area);
 }
 
-fn draw_poll_end_field(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPostState, cursor_visible: bool) {
-    let poll_end_title = if new_post_state.current_field == new_post::NewPostField::PollEnd {
-        "Poll End (ACTIVE)"
-    } else {
-        "Poll End"
+fn draw_poll_end_field(f: &mut Frame, area: Rect, new_post_state: &new_post::NewPostState, invalid: bool, cursor_visible: bool) {
+    let poll_end_title = match (invalid, new_post_state.current_field == new_post::NewPostField::PollEnd) {
+        (true, _) => "Poll End (INVALID)",
+        (false, true) => "Poll End (ACTIVE)",
+        (false, false) => "Poll End",
     };
-    let poll_end_style = if new_post_state.current_field == new_post::NewPostField::PollEnd {
+    let poll_end_style = if invalid {
+        Style::default().bg(Color::Black).fg(Color::Red)
+    } else if new_post_state.current_field == new_post::NewPostField::PollEnd {
         Style::default().bg(Color::Black).fg(Color::Yellow)
     } else {
         Style::default().bg(Color::Black)
@@ -315,11 +368,11 @@ fn draw_poll_end_field(f: &mut Frame, area: Rect, new_post_state: &new_post::New
     let poll_end_line = if new_post_state.poll_end.is_empty() {
         if new_post_state.current_field == new_post::NewPostField::PollEnd && cursor_visible {
             Line::from(vec![
-                Span::styled("ISO date", Style::default().fg(Color::Gray)),
+                Span::styled("ISO date, options as - [ ] lines", Style::default().fg(Color::Gray)),
                 Span::styled("█", Style::default().fg(Color::White).bg(Color::Gray)),
             ])
         } else {
-            Line::from(Span::styled("ISO date", Style::default().fg(Color::Gray)))
+            Line::from(Span::styled("ISO date, options as - [ ] lines", Style::default().fg(Color::Gray)))
         }
     } else {
         if new_post_state.current_field == new_post::NewPostField::PollEnd && cursor_visible {
@@ -1,7 +1,9 @@
 //! Post list UI component (both list and threaded views).
 
-use super::super::modes::ViewMode;
-use super::super::navigation::Navigator;
+use super::super::app::TUI;
+use super::super::modes::{NotificationFilter, ViewMode};
+use crate::config::Config;
+use crate::formatting;
 use org_social_lib_rs::{notifications, parser, threading};
 use ratatui::{
     layout::Rect,
@@ -11,25 +13,72 @@ use ratatui::{
     Frame,
 };
 
-/// Draw the post list (either list or threaded view or notifications)
-pub fn draw_post_list(f: &mut Frame, area: Rect, view_mode: &ViewMode, posts: &[parser::Post], notification_feed: &notifications::NotificationFeed, thread_view: &threading::ThreadView, navigator: &Navigator) {
-    match view_mode {
+/// Fewest characters of content shown for each post, whatever `list_preview_length` says
+const MIN_LIST_PREVIEW_LENGTH: usize = 5;
+
+/// Characters of content shown for each post in the list and threaded views
+fn list_preview_length(app: &TUI) -> usize {
+    app.config.list_preview_length.max(MIN_LIST_PREVIEW_LENGTH)
+}
+
+/// Draw the post list (either list or threaded view, notifications, bookmarks or own posts)
+pub fn draw_post_list(f: &mut Frame, area: Rect, app: &TUI) {
+    match app.view_mode {
         ViewMode::List => {
-            draw_list_view(f, area, posts, navigator);
+            let title = match app.active_filter() {
+                Some(query) => format!("Posts matching '{query}'{}", hidden_note(app)),
+                None => format!("Posts{}", hidden_note(app)),
+            };
+            draw_list_view(f, area, &app.visible_posts(), &title, app);
+        }
+        ViewMode::Bookmarks => {
+            draw_bookmarks_view(f, area, app);
+        }
+        ViewMode::OwnPosts => {
+            draw_own_posts_view(f, area, app);
         }
         ViewMode::Threaded => {
-            draw_threaded_view(f, area, thread_view, navigator);
+            draw_threaded_view(f, area, app);
         }
         ViewMode::Notifications => {
-            draw_notifications_view(f, area, notification_feed, navigator);
+            draw_notifications_view(f, area, app);
         }
     }
 }
 
-fn draw_list_view(f: &mut Frame, area: Rect, posts: &[parser::Post], navigator: &Navigator) {
+/// Draw the bookmarked posts like the list view
+fn draw_bookmarks_view(f: &mut Frame, area: Rect, app: &TUI) {
+    if app.bookmarked_posts.is_empty() {
+        let no_bookmarks = List::new(vec![ListItem::new("No bookmarks yet, press 'b' to bookmark a post")])
+            .block(Block::default().borders(Borders::ALL).title("Bookmarks (0/0)"))
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(no_bookmarks, area);
+        return;
+    }
+
+    let posts: Vec<&parser::Post> = app.bookmarked_posts.iter().collect();
+    draw_list_view(f, area, &posts, "Bookmarks", app);
+}
+
+/// Draw the posts written by the user like the list view
+fn draw_own_posts_view(f: &mut Frame, area: Rect, app: &TUI) {
+    if app.own_posts.is_empty() {
+        let no_posts = List::new(vec![ListItem::new("You haven't posted yet, press 'n' to write a post")])
+            .block(Block::default().borders(Borders::ALL).title("Own Posts (0/0)"))
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(no_posts, area);
+        return;
+    }
+
+    let posts: Vec<&parser::Post> = app.own_posts.iter().collect();
+    draw_list_view(f, area, &posts, "Own Posts", app);
+}
+
+fn draw_list_view(f: &mut Frame, area: Rect, posts: &[&parser::Post], title: &str, app: &TUI) {
+    let (navigator, config, theme) = (&app.navigator, &app.config, &app.theme);
     if posts.is_empty() {
         let no_posts = List::new(vec![ListItem::new("No posts available")])
-            .block(Block::default().borders(Borders::ALL).title("Posts (0/0)"))
+            .block(Block::default().borders(Borders::ALL).title(format!("{title} (0/0)")))
             .style(Style::default().fg(Color::Gray));
         f.render_widget(no_posts, area);
         return;
@@ -39,15 +88,18 @@ fn draw_list_view(f: &mut Frame, area: Rect, posts: &[parser::Post], navigator:
         .iter()
         .enumerate()
         .map(|(i, post)| {
-            let style = if i == navigator.selected_post {
+            let selected = i == navigator.selected_post;
+            let style = if selected {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if app.read_posts.contains(&post.full_id()) {
+                Style::default().add_modifier(Modifier::DIM)
             } else {
                 Style::default()
             };
 
             let author = post.author().as_ref().map(|s| s.as_str()).unwrap_or("unknown");
             let time_str = if let Some(time) = post.time() {
-                time.format("%m-%d %H:%M").to_string()
+                formatting::format_post_time(time, config.relative_timestamps, "%m-%d %H:%M", config.timezone)
             } else {
                 "no time".to_string()
             };
@@ -57,13 +109,17 @@ fn draw_list_view(f: &mut Frame, area: Rect, posts: &[parser::Post], navigator:
                 .next()
                 .unwrap_or("")
                 .chars()
-                .take(25)
+                .take(list_preview_length(app))
                 .collect::<String>();
 
+            let bookmark_marker = if app.bookmarks.contains(&post.full_id()) { "★ " } else { "" };
+
             let line = Line::from(vec![
-                Span::styled(format!("{author}: "), style.fg(Color::Green)),
+                Span::styled(bookmark_marker, style.fg(Color::Yellow)),
+                Span::styled(own_post_marker(app, post), style.fg(Color::Cyan)),
+                Span::styled(format!("{author}: "), style.fg(theme.author_color(author, config.author_colors && !selected))),
                 Span::styled(content_preview, style),
-                Span::styled(format!(" ({time_str})"), style.fg(Color::Blue)),
+                Span::styled(format!(" ({time_str})"), style.fg(theme.timestamp)),
             ]);
 
             ListItem::new(line)
@@ -77,17 +133,22 @@ fn draw_list_view(f: &mut Frame, area: Rect, posts: &[parser::Post], navigator:
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Posts ({}/{})", navigator.selected_post + 1, posts.len()))
+                .title(format!("{title} ({}/{})", navigator.selected_post + 1, posts.len()))
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(Style::default().bg(theme.selected));
 
     f.render_stateful_widget(posts_list, area, &mut list_state);
 }
 
-fn draw_threaded_view(f: &mut Frame, area: Rect, thread_view: &threading::ThreadView, navigator: &Navigator) {
+fn draw_threaded_view(f: &mut Frame, area: Rect, app: &TUI) {
+    let (thread_view, navigator, config, theme) = (app.visible_threads(), &app.navigator, &app.config, &app.theme);
+    let title = match app.active_filter() {
+        Some(query) => format!("Threads matching '{query}'{}", hidden_note(app)),
+        None => format!("Threads{}", hidden_note(app)),
+    };
     if thread_view.is_empty() {
         let no_posts = List::new(vec![ListItem::new("No posts available")])
-            .block(Block::default().borders(Borders::ALL).title("Threads (0/0)"))
+            .block(Block::default().borders(Borders::ALL).title(format!("{title} (0/0)")))
             .style(Style::default().fg(Color::Gray));
         f.render_widget(no_posts, area);
         return;
@@ -99,48 +160,66 @@ fn draw_threaded_view(f: &mut Frame, area: Rect, thread_view: &threading::Thread
 
     // Flatten all threads into a list with proper indentation
     for (thread_idx, thread) in thread_view.roots.iter().enumerate() {
-        let thread_posts = thread.flatten();
-        
-        for (post_idx, post) in thread_posts.iter().enumerate() {
+        let mut thread_posts = flatten_with_depth(thread, 0);
+        // Collapsed threads only show their root, with the number of hidden replies
+        let hidden_replies = if app.collapsed_threads.contains(&thread.post.full_id()) {
+            thread_posts.len() - 1
+        } else {
+            0
+        };
+        thread_posts.truncate(thread_posts.len() - hidden_replies);
+
+        for (post_idx, (depth, post)) in thread_posts.into_iter().enumerate() {
             if thread_idx == navigator.selected_thread && post_idx == navigator.selected_thread_post {
                 selected_global_index = global_index;
             }
 
-            let depth = if post_idx == 0 { 0 } else { 
-                // Calculate depth based on reply relationships
-                thread.replies.iter()
-                    .find_map(|reply| find_post_depth(reply, post.id(), 1))
-                    .unwrap_or(1)
-            };
-
             let indent = "  ".repeat(depth);
-            let style = if thread_idx == navigator.selected_thread && post_idx == navigator.selected_thread_post {
+            let selected = thread_idx == navigator.selected_thread && post_idx == navigator.selected_thread_post;
+            let style = if selected {
                 Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if app.read_posts.contains(&post.full_id()) {
+                Style::default().add_modifier(Modifier::DIM)
             } else {
                 Style::default()
             };
 
             let author = post.author().as_ref().map(|s| s.as_str()).unwrap_or("unknown");
             let time_str = if let Some(time) = post.time() {
-                time.format("%m-%d %H:%M").to_string()
+                formatting::format_post_time(time, config.relative_timestamps, "%m-%d %H:%M", config.timezone)
             } else {
                 "no time".to_string()
             };
 
-            let content_preview = post.content()
-                .lines()
-                .next()
-                .unwrap_or("")
-                .chars()
-                .take(25 - indent.len()) // Account for indentation
-                .collect::<String>();
+            // Muted posts are only in threads as the context of other posts
+            let content_preview = if app.is_muted(post) {
+                "(muted)".to_string()
+            } else {
+                post.content()
+                    .lines()
+                    .next()
+                    .unwrap_or("")
+                    .chars()
+                    .take(list_preview_length(app).saturating_sub(indent.len())) // Account for indentation
+                    .collect::<String>()
+            };
 
-            let line = Line::from(vec![
+            let mut spans = vec![
                 Span::styled(indent.to_string(), style),
-                Span::styled(format!("{author}: "), style.fg(Color::Green)),
+                Span::styled(own_post_marker(app, post), style.fg(Color::Cyan)),
+                Span::styled(format!("{author}: "), style.fg(theme.author_color(author, config.author_colors && !selected))),
                 Span::styled(content_preview, style),
-                Span::styled(format!(" ({time_str})"), style.fg(Color::Blue)),
-            ]);
+                Span::styled(format!(" ({time_str})"), style.fg(theme.timestamp)),
+            ];
+            if post_idx == 0 {
+                if let Some(activity) = thread_activity(thread, config) {
+                    spans.push(Span::styled(activity, style.fg(Color::DarkGray)));
+                }
+            }
+            if hidden_replies > 0 {
+                spans.push(Span::styled(format!(" [+{hidden_replies}]"), style.fg(Color::Cyan)));
+            }
+            let line = Line::from(spans);
 
             items.push(ListItem::new(line));
             global_index += 1;
@@ -154,41 +233,76 @@ fn draw_threaded_view(f: &mut Frame, area: Rect, thread_view: &threading::Thread
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Threads ({}/{} - {} total posts)", 
+                .title(format!("{title} ({}/{} - {} total posts)", 
                     navigator.selected_thread + 1, 
                     thread_view.thread_count(),
                     thread_view.total_posts()))
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(Style::default().bg(theme.selected));
 
     f.render_stateful_widget(posts_list, area, &mut list_state);
 }
 
-// Helper function to find the depth of a post in the reply tree
-fn find_post_depth(node: &threading::ThreadNode, target_id: &str, current_depth: usize) -> Option<usize> {
-    if node.post.id() == target_id {
-        return Some(current_depth);
+/// Number of muted posts for the list and threaded view titles, empty when none are hidden
+fn hidden_note(app: &TUI) -> String {
+    match app.muted_count() {
+        0 => String::new(),
+        count => format!(" - {count} hidden by filters"),
     }
-    
-    for reply in &node.replies {
-        if let Some(depth) = find_post_depth(reply, target_id, current_depth + 1) {
-            return Some(depth);
+}
+
+/// Marker shown before the author of the user's own posts, empty for others
+pub fn own_post_marker(app: &TUI, post: &parser::Post) -> String {
+    if app.is_own_post(post) {
+        format!("{} ", formatting::OWN_POST_MARKER)
+    } else {
+        String::new()
+    }
+}
+
+/// Number of posts in a thread and the time of the latest one, shown on its root.
+/// None for threads without replies, the time is left out when no post has one.
+fn thread_activity(thread: &threading::ThreadNode, config: &Config) -> Option<String> {
+    let posts = thread.flatten();
+    if posts.len() < 2 {
+        return None;
+    }
+    let count = posts.len();
+    Some(match posts.iter().filter_map(|post| post.time()).max() {
+        Some(time) => {
+            let last = formatting::format_post_time(time, config.relative_timestamps, "%m-%d %H:%M", config.timezone);
+            let ago = if config.relative_timestamps && last != "now" { " ago" } else { "" };
+            format!(" ({count} posts, last {last}{ago})")
         }
+        None => format!(" ({count} posts)"),
+    })
+}
+
+/// Flatten a thread in the same order as `ThreadNode::flatten`, with each post's nesting depth
+fn flatten_with_depth(node: &threading::ThreadNode, depth: usize) -> Vec<(usize, &parser::Post)> {
+    let mut posts = vec![(depth, &node.post)];
+    for reply in &node.replies {
+        posts.extend(flatten_with_depth(reply, depth + 1));
     }
-    
-    None
+    posts
 }
 
-fn draw_notifications_view(f: &mut Frame, area: Rect, notification_feed: &notifications::NotificationFeed, navigator: &Navigator) {
-    if notification_feed.notifications.is_empty() {
+fn draw_notifications_view(f: &mut Frame, area: Rect, app: &TUI) {
+    let (navigator, theme) = (&app.navigator, &app.theme);
+    let notifications = app.visible_notifications();
+    let title = match app.notification_filter {
+        NotificationFilter::All => "Notifications".to_string(),
+        filter => format!("Notifications - {} only", filter.display_name()),
+    };
+    if notifications.is_empty() {
         let no_notifications = List::new(vec![ListItem::new("No notifications")])
-            .block(Block::default().borders(Borders::ALL).title("Notifications (0/0)"))
+            .block(Block::default().borders(Borders::ALL).title(format!("{title} (0/0)")))
             .style(Style::default().fg(Color::Gray));
         f.render_widget(no_notifications, area);
         return;
     }
 
-    let items: Vec<ListItem> = notification_feed.notifications
+    let items: Vec<ListItem> = notifications
         .iter()
         .enumerate()
         .map(|(i, notification)| {
@@ -212,19 +326,14 @@ fn draw_notifications_view(f: &mut Frame, area: Rect, notification_feed: &notifi
 
             // Add author
             if let Some(author) = post.author() {
-                line.push(Span::styled(author.clone(), Style::default().fg(Color::Green)));
+                line.push(Span::styled(author.clone(), Style::default().fg(theme.author)));
                 line.push(Span::raw(": "));
             }
 
             // Add truncated content
             let content = post.content().trim().replace('\n', " ");
             let max_len = area.width.saturating_sub(30) as usize; // Leave space for type and author
-            let truncated = if content.len() > max_len {
-                format!("{}...", &content[..max_len.min(content.len())])
-            } else {
-                content
-            };
-            line.push(Span::raw(truncated));
+            line.push(Span::raw(formatting::truncate_chars(&content, max_len)));
 
             let style = if i == navigator.selected_post {
                 Style::default().bg(Color::Blue).fg(Color::White)
@@ -243,11 +352,38 @@ fn draw_notifications_view(f: &mut Frame, area: Rect, notification_feed: &notifi
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Notifications ({}/{})", 
-                    navigator.selected_post + 1, 
-                    notification_feed.notifications.len()))
+                .title(format!("{title} ({}/{})",
+                    navigator.selected_post + 1,
+                    notifications.len()))
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(Style::default().bg(theme.selected));
 
     f.render_stateful_widget(posts_list, area, &mut list_state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{full_id, org_post, parse_posts, SOURCE};
+
+    #[test]
+    fn flatten_with_depth_follows_a_four_level_reply_chain() {
+        let ids = ["2025-01-01T10:00:00+0000", "2025-01-01T11:00:00+0000", "2025-01-01T12:00:00+0000", "2025-01-01T13:00:00+0000"];
+        let org_posts: Vec<String> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let parent = (i > 0).then(|| full_id(ids[i - 1]));
+                org_post(id, parent.as_deref(), &format!("level {i}"))
+            })
+            .collect();
+        let thread_view = threading::ThreadView::from_posts(parse_posts(SOURCE, &org_posts));
+        assert_eq!(thread_view.roots.len(), 1);
+
+        let flattened: Vec<(usize, &str)> = flatten_with_depth(&thread_view.roots[0], 0)
+            .into_iter()
+            .map(|(depth, post)| (depth, post.id()))
+            .collect();
+        assert_eq!(flattened, vec![(0, ids[0]), (1, ids[1]), (2, ids[2]), (3, ids[3])]);
+    }
+}
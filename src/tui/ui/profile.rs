@@ -0,0 +1,87 @@
+//! Author profile overlay UI component.
+
+use super::super::theme::Theme;
+use org_social_lib_rs::parser::Profile;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+fn label(text: &str) -> Span<'static> {
+    Span::styled(text.to_string(), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+}
+
+/// Add a single value on the label's line, or a numbered list below it
+fn push_list(lines: &mut Vec<Line<'static>>, singular: &str, plural: &str, values: &[String], style: Style) {
+    match values {
+        [] => {}
+        [value] => lines.push(Line::from(vec![label(singular), Span::styled(value.clone(), style)])),
+        _ => {
+            lines.push(Line::from(label(plural)));
+            for (i, value) in values.iter().enumerate() {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}. ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(value.clone(), style),
+                ]));
+            }
+        }
+    }
+}
+
+/// Profile lines, laid out like `formatting::format_profile_colored` in the CLI
+fn profile_lines(profile: &Profile, theme: &Theme) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    if !profile.title().is_empty() {
+        lines.push(Line::from(vec![label("Title: "), Span::styled(profile.title().to_string(), Style::default().fg(Color::Cyan))]));
+    }
+    lines.push(Line::from(vec![
+        label("Nick: "),
+        Span::styled(profile.nick().to_string(), Style::default().fg(theme.author).add_modifier(Modifier::BOLD)),
+    ]));
+    if !profile.description().is_empty() {
+        lines.push(Line::from(vec![label("Description: "), Span::raw(profile.description().to_string())]));
+    }
+    if let Some(avatar) = profile.avatar() {
+        lines.push(Line::from(vec![label("Avatar: "), Span::styled(avatar.clone(), Style::default().fg(theme.link))]));
+    }
+
+    let link_style = Style::default().fg(theme.link).add_modifier(Modifier::UNDERLINED);
+    let links = profile.link().map_or(&[][..], Vec::as_slice);
+    push_list(&mut lines, "Link: ", "Links:", links, link_style);
+    let contacts = profile.contact().map_or(&[][..], Vec::as_slice);
+    push_list(&mut lines, "Contact: ", "Contact:", contacts, Style::default().fg(Color::Magenta));
+
+    if let Some(follows) = profile.follow().as_ref().filter(|follows| !follows.is_empty()) {
+        lines.push(Line::from(vec![
+            label("Following: "),
+            Span::styled(follows.len().to_string(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(if follows.len() == 1 { " user" } else { " users" }, Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+    if let Some(source) = profile.source() {
+        lines.push(Line::from(vec![label("Source: "), Span::styled(source.clone(), Style::default().fg(Color::DarkGray))]));
+    }
+    lines
+}
+
+/// Draw the author profile centered over the current screen
+pub fn draw_profile(f: &mut Frame, area: Rect, profile: &Profile, theme: &Theme) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Profile (Esc to close)")
+        .border_style(Style::default().fg(Color::Yellow));
+    let paragraph = Paragraph::new(profile_lines(profile, theme)).wrap(Wrap { trim: true });
+
+    // Size the popup to the wrapped profile, plus the borders
+    let width = area.width.saturating_sub(4).min(80);
+    let height = (paragraph.line_count(width.saturating_sub(2)) as u16 + 2).min(area.height);
+    let [_, column, _] = Layout::horizontal([Constraint::Fill(1), Constraint::Length(width), Constraint::Fill(1)]).areas(area);
+    let [_, popup, _] = Layout::vertical([Constraint::Fill(1), Constraint::Length(height), Constraint::Fill(1)]).areas(column);
+
+    f.render_widget(Clear, popup);
+    f.render_widget(paragraph.block(block), popup);
+}
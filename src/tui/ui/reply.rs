@@ -9,8 +9,8 @@ use ratatui::{
     Frame,
 };
 
-/// Draw the reply window overlay
-pub fn draw_reply_window(f: &mut Frame, area: Rect, reply_state: &reply::ReplyState, cursor_visible: bool) {
+/// Draw the reply window overlay, returning the area of the content field
+pub fn draw_reply_window(f: &mut Frame, area: Rect, reply_state: &reply::ReplyState, cursor_visible: bool, status_message: &Option<String>, char_limit: usize) -> Rect {
     // Create centered reply window
     let reply_area = Rect {
         x: area.width / 8,
@@ -50,12 +50,18 @@ pub fn draw_reply_window(f: &mut Frame, area: Rect, reply_state: &reply::ReplySt
     draw_mood_field(f, reply_chunks[3], reply_state, cursor_visible);
 
     // Help/Controls
-    let help_text = "Tab/Shift+Tab:switch fields | Enter/Shift+Enter:newline | Ctrl+S:submit | F1:remove last tag | Esc:cancel";
+    let help_text = "Tab/Shift+Tab:switch fields | Enter/Shift+Enter:newline | Ctrl+S:submit | F1:remove last tag | Esc:cancel | Ctrl+E:$EDITOR | Ctrl+Z/Ctrl+Y:undo/redo | Ctrl+Left/Right:move by word | Ctrl+W:delete word";
     let help = Paragraph::new(help_text)
-        .block(Block::default().borders(Borders::ALL).title("Controls"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(super::new_post::controls_title(status_message))
+                .title(super::new_post::length_counter(&reply_state.content, char_limit)),
+        )
         .wrap(Wrap { trim: true })
         .style(Style::default().bg(Color::Black).fg(Color::Green));
     f.render_widget(help, reply_chunks[4]);
+    reply_chunks[1]
 }
 
 fn draw_content_field(f: &mut Frame, area: Rect, reply_state: &reply::ReplyState, cursor_visible: bool) {
@@ -184,11 +190,11 @@ fn draw_mood_field(f: &mut Frame, area: Rect, reply_state: &reply::ReplyState, c
     let mood_content = if reply_state.mood.is_empty() && reply_state.current_field == reply::ReplyField::Mood {
         if cursor_visible {
             Line::from(vec![
-                Span::styled("Enter mood (optional)...", Style::default().fg(Color::DarkGray)),
+                Span::styled("Enter mood (optional), e.g. :smile:...", Style::default().fg(Color::DarkGray)),
                 Span::styled("█", Style::default().fg(Color::White).bg(Color::Gray)),
             ])
         } else {
-            Line::from(Span::styled("Enter mood (optional)...", Style::default().fg(Color::DarkGray)))
+            Line::from(Span::styled("Enter mood (optional), e.g. :smile:...", Style::default().fg(Color::DarkGray)))
         }
     } else {
         // Render mood with cursor if active
@@ -7,15 +7,33 @@ use ratatui::{
     Frame,
 };
 
-use super::super::modes::{AppMode, ViewMode};
+use super::super::app::TUI;
+use super::super::modes::AppMode;
+use crate::formatting;
+
+/// Current time and how long ago the feed was loaded
+fn clock(app: &TUI) -> String {
+    let loaded = match formatting::relative_time(app.loaded_at).as_str() {
+        "now" => "loaded just now".to_string(),
+        ago => format!("loaded {ago} ago"),
+    };
+    format!("{} | {loaded}", chrono::Local::now().format("%H:%M"))
+}
 
 /// Draw the status/input area
-pub fn draw_status_area(f: &mut Frame, area: Rect, mode: &AppMode, view_mode: &ViewMode, status_message: &Option<String>) {
-    let text = match mode {
+pub fn draw_status_area(f: &mut Frame, area: Rect, app: &TUI) {
+    let text = match &app.mode {
         AppMode::Browsing => {
-            let view_info = view_mode.display_name();
+            let view_info = format!(
+                "{}{} | 🔔 {}/{} | {}",
+                app.view_mode.display_name(),
+                if app.focus_mode { " (focus mode)" } else { "" },
+                app.unread_notification_count(),
+                app.notification_feed.notifications.len(),
+                clock(app)
+            );
             
-            if let Some(msg) = status_message {
+            if let Some(msg) = &app.status_message {
                 Text::from(vec![
                     Line::from(msg.clone()),
                     Line::from(format!("{view_info} | q:quit | j/k:nav | d/u:scroll | g/G:top/bottom | t:toggle view | r:reply | n:new post | h:help")),
@@ -33,9 +51,29 @@ pub fn draw_status_area(f: &mut Frame, area: Rect, mode: &AppMode, view_mode: &V
         AppMode::Help => {
             Text::from("Showing help - press h or Esc to close")
         }
+        AppMode::Command => {
+            let cursor = if app.cursor_visible { "█" } else { " " };
+            Text::from(vec![
+                Line::from(format!(":{}{cursor}", app.command_input)),
+                Line::from("Go to post ID - Enter to jump, Esc to cancel"),
+            ])
+        }
         AppMode::PollVote => {
             Text::from("Poll voting mode - use j/k to select, Enter to vote, Esc to cancel")
         }
+        AppMode::Filter => {
+            let cursor = if app.cursor_visible { "█" } else { " " };
+            Text::from(vec![
+                Line::from(format!("/{}{cursor}", app.filter_input)),
+                Line::from("Filter posts by content and author - Enter to keep the filter, Esc to clear it"),
+            ])
+        }
+        AppMode::Profile => {
+            Text::from("Showing the author's profile - press p or Esc to close")
+        }
+        AppMode::ConfirmDiscard => {
+            Text::from("Leaving the editor - d:discard | k:keep editing | s:save draft")
+        }
     };
 
     let status = Paragraph::new(text)
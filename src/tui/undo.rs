@@ -0,0 +1,67 @@
+//! Undo and redo for the content of posts and replies being written.
+
+use std::collections::VecDeque;
+
+/// Maximum number of undo steps kept, older ones are dropped
+const MAX_UNDO_STEPS: usize = 100;
+
+/// Content and cursor position at some point while writing
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub content: String,
+    pub cursor: usize,
+}
+
+/// Kind of change made to the content
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditKind {
+    Insert,
+    Delete,
+    /// The whole content was replaced, e.g. by `$EDITOR`
+    Replace,
+}
+
+/// Undo and redo stacks of the content being written
+#[derive(Default)]
+pub struct UndoHistory {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<EditKind>,
+}
+
+impl UndoHistory {
+    /// Record the content before an edit. Consecutive edits of the same kind are undone together,
+    /// a new step starts at a boundary (whitespace or a newline) or when switching between typing and deleting.
+    pub fn record(&mut self, before: Snapshot, kind: EditKind, boundary: bool) {
+        let new_step = boundary || kind == EditKind::Replace || self.last_edit != Some(kind);
+        if new_step && self.undo.back() != Some(&before) {
+            self.undo.push_back(before);
+            if self.undo.len() > MAX_UNDO_STEPS {
+                self.undo.pop_front();
+            }
+        }
+        self.last_edit = Some(kind);
+        self.redo.clear();
+    }
+
+    /// Start a new undo step with the next edit, e.g. after moving the cursor
+    pub fn end_step(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Step back, returning the content to restore
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        self.last_edit = None;
+        Some(previous)
+    }
+
+    /// Step forward again after undoing, returning the content to restore
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        self.last_edit = None;
+        Some(next)
+    }
+}
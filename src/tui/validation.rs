@@ -0,0 +1,51 @@
+//! Checks run on new posts before they are written to the social file.
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone};
+use org_social_lib_rs::{parser, poll};
+
+/// Formats without a timezone, read as local time
+const NAIVE_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"];
+
+/// Parse a poll end like `2025-01-01`, `2025-01-01T12:00` or `2025-01-01T12:00:00Z`.
+/// Dates and times without a timezone are in local time, a date alone means its midnight.
+pub fn parse_poll_end(input: &str) -> Option<DateTime<FixedOffset>> {
+    let input = input.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Some(time);
+    }
+    if let Ok(time) = DateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S%z") {
+        return Some(time);
+    }
+    let naive = NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+        .or_else(|| NaiveDate::parse_from_str(input, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))?;
+    Local.from_local_datetime(&naive).earliest().map(|time| time.fixed_offset())
+}
+
+/// Poll end in the RFC 3339 form read back by the poll parser, empty when there is no poll.
+/// Returns the error to show when the input isn't a date.
+pub fn normalize_poll_end(input: &str) -> Result<String, String> {
+    if input.trim().is_empty() {
+        return Ok(String::new());
+    }
+    parse_poll_end(input)
+        .map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .ok_or_else(|| format!("Invalid poll end '{}', use a date like 2025-01-01 or 2025-01-01T12:00:00Z", input.trim()))
+}
+
+/// Check that the content of a post with a poll end lists at least two poll options,
+/// so it is read back as a poll. Returns the error to show otherwise.
+pub fn check_poll_options(content: &str, poll_end: &str) -> Result<(), String> {
+    let mut post = parser::Post::new(String::new(), content.to_string());
+    post.set_poll_end(Some(poll_end.to_string()));
+    if poll::parse_poll_from_post(&post).is_some() {
+        return Ok(());
+    }
+    let options = content.lines().filter(|line| line.trim().starts_with("- [ ]")).count();
+    Err(if options == 0 {
+        "A poll needs its options in the content, one per line starting with '- [ ]'".to_string()
+    } else {
+        "A poll needs at least two options in the content, on consecutive lines starting with '- [ ]'".to_string()
+    })
+}
@@ -0,0 +1,40 @@
+//! Word-wise cursor movement in the content being written.
+//!
+//! Cursors are byte offsets into the content, as used by the post and reply states,
+//! and are always moved to char boundaries. Runs of whitespace separate words.
+
+/// Convert a position in chars to a byte offset into `text`
+pub fn char_pos_to_byte_pos(text: &str, char_pos: usize) -> usize {
+    text.char_indices().nth(char_pos).map_or(text.len(), |(i, _)| i)
+}
+
+/// Convert a byte offset into `text` to a position in chars
+pub fn byte_pos_to_char_pos(text: &str, byte_pos: usize) -> usize {
+    text.char_indices().take_while(|(i, _)| *i < byte_pos).count()
+}
+
+/// Start of the word before the cursor, skipping any whitespace in between
+pub fn prev_word_start(text: &str, cursor: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = byte_pos_to_char_pos(text, cursor);
+    while pos > 0 && chars[pos - 1].is_whitespace() {
+        pos -= 1;
+    }
+    while pos > 0 && !chars[pos - 1].is_whitespace() {
+        pos -= 1;
+    }
+    char_pos_to_byte_pos(text, pos)
+}
+
+/// End of the word after the cursor, skipping any whitespace in between
+pub fn next_word_end(text: &str, cursor: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = byte_pos_to_char_pos(text, cursor);
+    while pos < chars.len() && chars[pos].is_whitespace() {
+        pos += 1;
+    }
+    while pos < chars.len() && !chars[pos].is_whitespace() {
+        pos += 1;
+    }
+    char_pos_to_byte_pos(text, pos)
+}